@@ -0,0 +1,49 @@
+//! Compares the `pow10_*` lookup tables with the iterator-based computation they replaced (run with `cargo bench --bench pow10`)
+
+use std::hint::black_box;
+use std::time::Instant;
+use timestamp_please::{pow10_u128, scale_u128};
+
+const ITERATIONS: u64 = 10_000_000;
+
+/// The implementation of `pow10_u128` before the lookup table
+fn computed_pow10_u128(exp: u32) -> Option<u128> {
+    if exp > 38 {
+        return None;
+    }
+
+    core::iter::repeat_n(10u128, exp as usize).try_fold(1u128, |acc, value| acc.checked_mul(value))
+}
+
+/// The implementation of `scale_u128` on top of [`computed_pow10_u128`]
+fn computed_scale_u128(value: u128, exp: i64) -> Option<u128> {
+    if value == 0 {
+        return Some(0);
+    }
+
+    let factor = u32::try_from(exp.unsigned_abs())
+        .ok()
+        .and_then(computed_pow10_u128);
+    match (exp >= 0, factor) {
+        (true, Some(factor)) => value.checked_mul(factor),
+        (true, None) => None,
+        (false, Some(factor)) => Some(value / factor),
+        (false, None) => Some(0),
+    }
+}
+
+fn bench(name: &str, f: impl Fn(u64) -> u128) {
+    let start = Instant::now();
+    let checksum = (0..ITERATIONS)
+        .map(|i| black_box(f(black_box(i))))
+        .fold(0u128, u128::wrapping_add);
+    let elapsed = start.elapsed();
+    println!("{name}: {:.2} ns/iter (checksum {checksum})", elapsed.as_nanos() as f64 / ITERATIONS as f64);
+}
+
+fn main() {
+    bench("computed pow10_u128", |i| computed_pow10_u128((i % 39) as u32).unwrap_or(0));
+    bench("table pow10_u128", |i| pow10_u128((i % 39) as u32).unwrap_or(0));
+    bench("computed scale_u128", |i| computed_scale_u128(u128::from(i) << 64, -((i % 30) as i64)).unwrap_or(0));
+    bench("table scale_u128", |i| scale_u128(u128::from(i) << 64, -((i % 30) as i64)).unwrap_or(0));
+}
@@ -0,0 +1,29 @@
+//! Measures `try_scale` on `u64` storage, which widens to `u128` internally (run with `cargo bench --bench scale`)
+
+use std::hint::black_box;
+use std::time::Instant;
+use timestamp_please::Timestamp;
+
+const ITERATIONS: u64 = 10_000_000;
+
+fn bench(name: &str, scale: impl Fn(u64) -> u64) {
+    let start = Instant::now();
+    let checksum = (0..ITERATIONS)
+        .map(|value| black_box(scale(black_box(value))))
+        .fold(0u64, u64::wrapping_add);
+    let elapsed = start.elapsed();
+    println!("{name}: {:.2} ns/iter (checksum {checksum})", elapsed.as_nanos() as f64 / ITERATIONS as f64);
+}
+
+fn main() {
+    bench("try_scale ms -> s", |value| {
+        Timestamp::<u64, -3>::new(value)
+            .try_scale_into_value::<0>()
+            .unwrap_or(0)
+    });
+    bench("try_scale s -> ms", |value| {
+        Timestamp::<u64, 0>::new(value)
+            .try_scale_into_value::<-3>()
+            .unwrap_or(0)
+    });
+}
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use timestamp_please::scale_u128;
+
+fuzz_target!(|input: (u128, i8, i8)| {
+    let (value, power_in, power_out) = input;
+    let exp = i64::from(power_in) - i64::from(power_out);
+    let scaled = scale_u128(value, exp);
+    // scaling into a finer unit is lossless, so scaling back must recover the value
+    if exp >= 0 {
+        if let Some(scaled) = scaled {
+            assert_eq!(scale_u128(scaled, -exp), Some(value));
+        }
+    } else {
+        assert!(scaled.is_some_and(|scaled| scaled <= value));
+    }
+});
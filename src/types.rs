@@ -1,2 +1,22 @@
 mod timestamp;
 pub use timestamp::*;
+mod display_with_unit;
+pub use display_with_unit::*;
+mod dyn_timestamp;
+pub use dyn_timestamp::*;
+mod display_with_separator;
+pub use display_with_separator::*;
+mod fixed_point_writer;
+pub use fixed_point_writer::*;
+mod display_with_max_fraction;
+pub use display_with_max_fraction::*;
+mod rounding_mode;
+pub use rounding_mode::*;
+mod display_scientific;
+pub use display_scientific::*;
+mod overflow_policy;
+pub use overflow_policy::*;
+mod rendered_seconds;
+pub use rendered_seconds::*;
+mod scale_failure_reason;
+pub use scale_failure_reason::*;
@@ -0,0 +1,23 @@
+use crate::{Timestamp, format_scientific_normalized};
+use core::fmt;
+
+/// Displays a [`Timestamp`] in seconds in scientific notation with one digit before the decimal point (e.g. `1.7e9` for `Timestamp<u64, UNO>::new(1700000000)`)
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayScientific<'a, V, const POWER: i32> {
+    timestamp: &'a Timestamp<V, POWER>,
+}
+
+impl<'a, V, const POWER: i32> DisplayScientific<'a, V, POWER> {
+    #[inline]
+    pub const fn new(timestamp: &'a Timestamp<V, POWER>) -> Self {
+        Self {
+            timestamp,
+        }
+    }
+}
+
+impl<V: itoa::Integer, const POWER: i32> fmt::Display for DisplayScientific<'_, V, POWER> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_scientific_normalized(*self.timestamp.as_ref(), POWER, f)
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{Timestamp, format_fixed_point_with_max_fraction};
+use core::fmt;
+
+/// Displays a [`Timestamp`] in fixed-point seconds with at most `max_frac_digits` fractional digits, truncating the rest (e.g. `1.123` for `Timestamp<u64, NANO>::new(1_123_456_789)` and `3`)
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayWithMaxFraction<'a, V, const POWER: i32> {
+    timestamp: &'a Timestamp<V, POWER>,
+    max_frac_digits: usize,
+}
+
+impl<'a, V, const POWER: i32> DisplayWithMaxFraction<'a, V, POWER> {
+    #[inline]
+    pub const fn new(timestamp: &'a Timestamp<V, POWER>, max_frac_digits: usize) -> Self {
+        Self {
+            timestamp,
+            max_frac_digits,
+        }
+    }
+}
+
+impl<V: itoa::Integer, const POWER: i32> fmt::Display for DisplayWithMaxFraction<'_, V, POWER> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_fixed_point_with_max_fraction(*self.timestamp.as_ref(), POWER, self.max_frac_digits, f)
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{Timestamp, format_fixed_point_with_separator};
+use core::fmt;
+
+/// Displays a [`Timestamp`] in fixed-point seconds with a custom decimal separator (e.g. `1,500` for `Timestamp<u64, MILLI>::new(1500)` and `,`)
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayWithSeparator<'a, V, const POWER: i32> {
+    timestamp: &'a Timestamp<V, POWER>,
+    separator: char,
+}
+
+impl<'a, V, const POWER: i32> DisplayWithSeparator<'a, V, POWER> {
+    #[inline]
+    pub const fn new(timestamp: &'a Timestamp<V, POWER>, separator: char) -> Self {
+        Self {
+            timestamp,
+            separator,
+        }
+    }
+}
+
+impl<V: itoa::Integer, const POWER: i32> fmt::Display for DisplayWithSeparator<'_, V, POWER> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_fixed_point_with_separator(*self.timestamp.as_ref(), POWER, self.separator, f)
+    }
+}
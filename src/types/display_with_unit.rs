@@ -0,0 +1,32 @@
+use crate::{MICRO, MILLI, NANO, Timestamp, UNO};
+use core::fmt;
+
+/// Displays the raw value of a [`Timestamp`] followed by its unit (e.g. `1700000000000ms`)
+///
+/// Falls back to `e{POWER}` for non-standard powers (e.g. `15e-2`).
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayWithUnit<'a, V, const POWER: i32> {
+    timestamp: &'a Timestamp<V, POWER>,
+}
+
+impl<'a, V, const POWER: i32> DisplayWithUnit<'a, V, POWER> {
+    #[inline]
+    pub const fn new(timestamp: &'a Timestamp<V, POWER>) -> Self {
+        Self {
+            timestamp,
+        }
+    }
+}
+
+impl<V: fmt::Display, const POWER: i32> fmt::Display for DisplayWithUnit<'_, V, POWER> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.timestamp.as_ref();
+        match POWER {
+            UNO => write!(f, "{value}s"),
+            MILLI => write!(f, "{value}ms"),
+            MICRO => write!(f, "{value}µs"),
+            NANO => write!(f, "{value}ns"),
+            _ => write!(f, "{value}e{POWER}"),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{Timestamp, format_fixed_point};
+use core::fmt;
+use core::num::ParseIntError;
+
+/// Fixed-point Unix timestamp with a runtime power: `value * 10^power` seconds since Unix epoch.
+///
+/// Unlike [`Timestamp`], timestamps with different powers have the same type, so they can be stored together (e.g. in a `Vec<DynTimestamp>`).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct DynTimestamp<Value = u64> {
+    #[cfg_attr(feature = "serde", serde(alias = "ts"))]
+    value: Value,
+    #[cfg_attr(feature = "serde", serde(alias = "p"))]
+    power: i32,
+}
+
+impl<V> DynTimestamp<V> {
+    #[inline]
+    pub const fn new(value: V, power: i32) -> Self {
+        Self {
+            value,
+            power,
+        }
+    }
+
+    #[inline]
+    pub fn into_value(self) -> V {
+        self.value
+    }
+
+    #[inline]
+    pub const fn power(&self) -> i32 {
+        self.power
+    }
+
+    #[inline]
+    pub fn format_as_seconds(&self, f: &mut impl fmt::Write) -> fmt::Result
+    where
+        V: itoa::Integer,
+    {
+        format_fixed_point(self.value, self.power, f)
+    }
+}
+
+impl DynTimestamp {
+    /// Parses a raw decimal integer (e.g. from a CLI argument) with a power chosen at runtime (e.g. from a `--unit` flag)
+    #[inline]
+    pub fn parse(s: &str, power: i32) -> Result<Self, ParseIntError> {
+        s.parse().map(|value| Self::new(value, power))
+    }
+}
+
+impl<V, const POWER: i32> From<Timestamp<V, POWER>> for DynTimestamp<V> {
+    #[inline]
+    fn from(timestamp: Timestamp<V, POWER>) -> Self {
+        Self::new(timestamp.into_value(), POWER)
+    }
+}
+
+/// Renders the value in fixed-point seconds (e.g. `1.500` for value `1500` and power `-3`), because the raw value is ambiguous without the power
+impl<V: itoa::Integer> fmt::Display for DynTimestamp<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format_as_seconds(f)
+    }
+}
@@ -0,0 +1,71 @@
+use core::fmt;
+
+/// Inserts a decimal separator into an integer that is written through it
+///
+/// Expects the integer to be written as an optional `-` followed by decimal digits (like the `Display` impls of the primitive integers). If `inner` is `None`, the writer only counts the digits.
+pub struct FixedPointWriter<'a> {
+    inner: Option<&'a mut dyn fmt::Write>,
+    separator: char,
+    split: Option<usize>,
+    skip_sign: bool,
+    digits: usize,
+    negative: bool,
+    nonzero: bool,
+}
+
+impl<'a> FixedPointWriter<'a> {
+    /// Creates a writer that inserts `separator` after `split` digits (if `split` is `Some`) and skips the `-` sign (if `skip_sign` is `true`)
+    #[inline]
+    pub fn new(inner: Option<&'a mut dyn fmt::Write>, separator: char, split: Option<usize>, skip_sign: bool) -> Self {
+        Self {
+            inner,
+            separator,
+            split,
+            skip_sign,
+            digits: 0,
+            negative: false,
+            nonzero: false,
+        }
+    }
+
+    /// Returns the count of digits written so far
+    #[inline]
+    pub fn digits(&self) -> usize {
+        self.digits
+    }
+
+    /// Returns `true` if a `-` sign was written
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns `true` if all digits written so far are zeros
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        !self.nonzero
+    }
+}
+
+impl fmt::Write for FixedPointWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|ch| {
+            if ch == '-' {
+                self.negative = true;
+                return match (&mut self.inner, self.skip_sign) {
+                    (Some(inner), false) => inner.write_char(ch),
+                    _ => Ok(()),
+                };
+            }
+            if let Some(inner) = &mut self.inner {
+                if self.split == Some(self.digits) {
+                    inner.write_char(self.separator)?;
+                }
+                inner.write_char(ch)?;
+            }
+            self.digits += 1;
+            self.nonzero |= ch != '0';
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,17 @@
+/// Controls what happens when a conversion overflows the storage type (see [`Timestamp::convert_with_policy`](crate::Timestamp::convert_with_policy))
+///
+/// The existing conversions use fixed policies:
+///
+/// - `Error`: [`Timestamp::try_scale`](crate::Timestamp::try_scale), the `TryFrom` impls, and the `checked_*` and `try_*` methods
+/// - `Saturate`: [`Add`](core::ops::Add), [`Timestamp::to_duration`](crate::Timestamp::to_duration) and the `saturating_*` methods
+/// - `Wrap`: [`Timestamp::wrapping_scale`](crate::Timestamp::wrapping_scale)
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub enum OverflowPolicy {
+    /// Clamps the result to the max value of the storage type
+    Saturate,
+    /// Returns an error
+    #[default]
+    Error,
+    /// Wraps the result modulo `2^BITS` of the storage type
+    Wrap,
+}
@@ -0,0 +1,29 @@
+use crate::{Timestamp, format_fixed_point_display};
+use core::fmt;
+
+/// Displays the [`fmt::Debug`] output of the raw value of a [`Timestamp`] in fixed-point seconds (used by the alternate `Debug` form of [`Timestamp`])
+#[derive(Copy, Clone)]
+pub struct RenderedSeconds<'a, V, const POWER: i32> {
+    timestamp: &'a Timestamp<V, POWER>,
+}
+
+impl<'a, V, const POWER: i32> RenderedSeconds<'a, V, POWER> {
+    #[inline]
+    pub const fn new(timestamp: &'a Timestamp<V, POWER>) -> Self {
+        Self {
+            timestamp,
+        }
+    }
+}
+
+impl<V: fmt::Debug, const POWER: i32> fmt::Display for RenderedSeconds<'_, V, POWER> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_fixed_point_display(&format_args!("{:?}", self.timestamp.as_ref()), POWER, f)
+    }
+}
+
+impl<V: fmt::Debug, const POWER: i32> fmt::Debug for RenderedSeconds<'_, V, POWER> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{self}\"")
+    }
+}
@@ -0,0 +1,11 @@
+/// Controls how the dropped digits are handled when scaling into a coarser unit (see [`Timestamp::try_scale_with_rounding`](crate::Timestamp::try_scale_with_rounding))
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub enum RoundingMode {
+    /// Drops the digits (e.g. `2999ms` into `2s`)
+    #[default]
+    Truncate,
+    /// Rounds to the nearest unit, rounding halves up (e.g. `2500ms` into `3s`)
+    Nearest,
+    /// Rounds to the nearest unit, rounding halves to the even unit, also known as banker's rounding (e.g. `2500ms` into `2s` and `3500ms` into `4s`)
+    NearestEven,
+}
@@ -0,0 +1,37 @@
+use core::fmt;
+
+/// Explains why [`TimestampTryScaleError`](crate::TimestampTryScaleError) occurred
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ScaleFailureReason {
+    /// The scaling factor `10^(power_in - power_out)` doesn't fit into the intermediate integer type
+    ExponentOutOfRange,
+    /// The scaled value doesn't fit into the intermediate integer type or the storage type
+    ValueTooLarge,
+    /// The value has non-zero digits below the target unit, so an exact conversion is impossible
+    Inexact,
+}
+
+impl ScaleFailureReason {
+    /// Returns the reason for a failed scaling from `power_in` to `power_out` in an intermediate integer type that can hold `10^max_pow10`
+    #[inline]
+    pub const fn new(power_in: i32, power_out: i32, max_pow10: u64) -> Self {
+        use ScaleFailureReason::*;
+        if power_in as i64 - power_out as i64 > max_pow10 as i64 {
+            ExponentOutOfRange
+        } else {
+            ValueTooLarge
+        }
+    }
+}
+
+impl fmt::Display for ScaleFailureReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ScaleFailureReason::*;
+        match self {
+            ExponentOutOfRange => f.write_str("exponent out of range"),
+            ValueTooLarge => f.write_str("value too large after scaling"),
+            Inexact => f.write_str("value has non-zero digits below the target unit"),
+        }
+    }
+}
@@ -1,19 +1,28 @@
+use crate::{DisplayScientific, DisplayWithMaxFraction, DisplayWithSeparator, DisplayWithUnit, DynTimestamp, FixedPointWriter, OverflowPolicy, RenderedSeconds, RoundingMode, ScaleFailureReason};
 use core::borrow::{Borrow, BorrowMut};
 use core::fmt;
-use core::ops::{Deref, DerefMut};
+use core::ops::{Add, AddAssign, Deref, DerefMut, Div, Rem, Sub, SubAssign};
 use core::time::Duration;
 
 const MAX_POW10_U128: u64 = 38;
+const MAX_POW10_U64: u64 = 19;
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+const SECS_PER_DAY: i128 = 86_400;
+/// Seconds from the NTP epoch (1900-01-01) to Unix epoch
+const NTP_UNIX_EPOCH_SECS: u64 = 2_208_988_800;
 
 /// Fixed-point Unix timestamp: `value * 10^POWER` seconds since Unix epoch.
 ///
 /// - `Value`: integer-like storage (e.g. `u64`)
 /// - `POWER`: base-10 exponent (e.g. `-3` for milliseconds)
+///
+/// [`Hash`] and [`Eq`] consider only the raw value, because `POWER` is part of the type. Timestamps with different powers are different types, so they must be scaled into a common power (e.g. with [`Timestamp::try_scale`]) before being used as keys in the same map.
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 #[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
 pub struct Timestamp<Value = u64, const POWER: i32 = 0> {
     value: Value,
 }
@@ -31,42 +40,514 @@ impl<V, const POWER: i32> Timestamp<V, POWER> {
         self.value
     }
 
+    /// Converts the value into another storage type (e.g. `u32` from `u64`), returning an error if it doesn't fit
+    #[inline]
+    pub fn try_into_value_as<W: TryFrom<V>>(self) -> Result<W, W::Error> {
+        W::try_from(self.value)
+    }
+
+    /// Converts the storage type (e.g. `u64` into `u128`), returning an error if the value doesn't fit
+    #[inline]
+    pub fn try_convert_storage<W: TryFrom<V>>(self) -> Result<Timestamp<W, POWER>, W::Error> {
+        self.try_into_value_as().map(Timestamp::new)
+    }
+
+    /// Returns a [`fmt::Display`] adapter that renders the raw value followed by the unit (e.g. `1700000000000ms`)
+    #[inline]
+    pub const fn display_with_unit(&self) -> DisplayWithUnit<'_, V, POWER> {
+        DisplayWithUnit::new(self)
+    }
+
+    /// Returns a [`fmt::Display`] adapter that renders the value in fixed-point seconds (e.g. `1.500`)
+    ///
+    /// Useful for structured logging, e.g. `tracing::info!(ts = %timestamp.display_as_seconds())` (`tracing::Value` is sealed, so it can't be implemented directly).
+    #[inline]
+    pub const fn display_as_seconds(&self) -> DisplayWithSeparator<'_, V, POWER> {
+        DisplayWithSeparator::new(self, '.')
+    }
+
+    /// Returns a [`fmt::Display`] adapter that renders the value in fixed-point seconds with `separator` instead of the decimal point (e.g. `1,500`)
+    #[inline]
+    pub const fn display_with_separator(&self, separator: char) -> DisplayWithSeparator<'_, V, POWER> {
+        DisplayWithSeparator::new(self, separator)
+    }
+
+    /// Returns a [`fmt::Display`] adapter that renders the value in fixed-point seconds with at most `max_frac_digits` fractional digits, truncating the rest (e.g. `1.123` for `1.123456789` and `3`)
+    #[inline]
+    pub const fn display_with_max_fraction(&self, max_frac_digits: usize) -> DisplayWithMaxFraction<'_, V, POWER> {
+        DisplayWithMaxFraction::new(self, max_frac_digits)
+    }
+
+    /// Returns a [`fmt::Display`] adapter that renders the value in seconds in normalized scientific notation (e.g. `1.7e9`)
+    #[inline]
+    pub const fn display_scientific_normalized(&self) -> DisplayScientific<'_, V, POWER> {
+        DisplayScientific::new(self)
+    }
+
+    /// Erases the power from the type (see [`DynTimestamp`])
+    #[inline]
+    pub fn to_dyn(self) -> DynTimestamp<V> {
+        DynTimestamp::from(self)
+    }
+
     #[inline]
     pub fn format_as_seconds(&self, f: &mut impl fmt::Write) -> fmt::Result
     where
         V: itoa::Integer,
     {
-        let mut buffer = itoa::Buffer::new();
-        let raw = buffer.format(self.value);
-        let (sign, digits) = raw.strip_prefix('-').map_or(("", raw), |rest| ("-", rest));
-        let power = i64::from(POWER);
+        format_fixed_point(self.value, POWER, f)
+    }
+
+    /// Same as [`Self::format_as_seconds`], but requires only [`fmt::Display`] (see [`format_fixed_point_display`])
+    #[inline]
+    pub fn format_as_seconds_generic(&self, f: &mut impl fmt::Write) -> fmt::Result
+    where
+        V: fmt::Display,
+    {
+        format_fixed_point_display(&self.value, POWER, f)
+    }
+
+    /// Returns the length of one unit in seconds as a `(numerator, denominator)` fraction (e.g. `(1, 1000)` for milliseconds), or `None` if `10^|POWER|` overflows `u128`
+    #[inline]
+    pub const fn seconds_per_unit_numerator_denominator() -> Option<(u128, u128)> {
+        match pow10_u128(POWER.unsigned_abs()) {
+            Some(factor) if POWER >= 0 => Some((factor, 1)),
+            Some(factor) => Some((1, factor)),
+            None => None,
+        }
+    }
+
+    /// Returns the length of one unit in seconds (`10^POWER`, e.g. `0.001` for milliseconds)
+    #[inline]
+    pub fn scale_factor_f64() -> f64 {
+        scale_f64(1.0, i64::from(POWER))
+    }
+}
+
+impl<V: Copy, const POWER: i32> Timestamp<V, POWER> {
+    /// Returns the raw value (usable in `const` contexts, unlike [`Deref`])
+    #[inline]
+    pub const fn value(&self) -> V {
+        self.value
+    }
+}
+
+impl<V: Into<u128> + TryFrom<u128>, const POWER: i32> Timestamp<V, POWER> {
+    /// Converts the value into `POWER_OUT` units (digits below the target unit are truncated)
+    #[inline]
+    pub fn try_scale<const POWER_OUT: i32>(self) -> Result<Timestamp<V, POWER_OUT>, TimestampTryScaleError> {
+        use TimestampTryScaleError::*;
+        let value = self.value.into();
+        scale_u128(value, i64::from(POWER) - i64::from(POWER_OUT))
+            .and_then(|scaled| V::try_from(scaled).ok())
+            .map(Timestamp::new)
+            .ok_or(ScaleFailed {
+                value,
+                power_in: POWER,
+                power_out: POWER_OUT,
+                reason: ScaleFailureReason::new(POWER, POWER_OUT, MAX_POW10_U128),
+            })
+    }
+
+    /// Same as [`Self::try_scale`], but rounds the digits below the target unit according to `mode`
+    #[inline]
+    pub fn try_scale_with_rounding<const POWER_OUT: i32>(self, mode: RoundingMode) -> Result<Timestamp<V, POWER_OUT>, TimestampTryScaleError> {
+        use TimestampTryScaleError::*;
+        let value = self.value.into();
+        scale_u128_with_rounding(value, i64::from(POWER) - i64::from(POWER_OUT), mode)
+            .and_then(|scaled| V::try_from(scaled).ok())
+            .map(Timestamp::new)
+            .ok_or(ScaleFailed {
+                value,
+                power_in: POWER,
+                power_out: POWER_OUT,
+                reason: ScaleFailureReason::new(POWER, POWER_OUT, MAX_POW10_U128),
+            })
+    }
+
+    /// Same as [`Self::try_scale`], but returns the raw value
+    #[inline]
+    pub fn try_scale_into_value<const POWER_OUT: i32>(self) -> Result<V, TimestampTryScaleError> {
+        self.try_scale::<POWER_OUT>().map(Timestamp::into_value)
+    }
+
+    /// Scales the value into a power chosen at runtime (e.g. from config), truncating the digits below the target unit, and returns the raw `(value, power)` pair, or `None` on overflow
+    #[inline]
+    pub fn scale_to_runtime_power(self, power: i32) -> Option<(u128, i32)> {
+        scale_u128(self.value.into(), i64::from(POWER) - i64::from(power)).map(|value| (value, power))
+    }
+
+    /// Same as [`Self::try_scale`] into seconds
+    #[inline]
+    pub fn to_seconds(self) -> Result<Timestamp<V, UNO>, TimestampTryScaleError> {
+        self.try_scale()
+    }
+
+    /// Same as [`Self::try_scale`] into milliseconds
+    #[inline]
+    pub fn to_millis(self) -> Result<Timestamp<V, MILLI>, TimestampTryScaleError> {
+        self.try_scale()
+    }
+
+    /// Same as [`Self::try_scale`] into microseconds
+    #[inline]
+    pub fn to_micros(self) -> Result<Timestamp<V, MICRO>, TimestampTryScaleError> {
+        self.try_scale()
+    }
+
+    /// Same as [`Self::try_scale`] into nanoseconds
+    #[inline]
+    pub fn to_nanos(self) -> Result<Timestamp<V, NANO>, TimestampTryScaleError> {
+        self.try_scale()
+    }
+}
+
+impl<const POWER: i32> Timestamp<i64, POWER> {
+    /// Same as [`Timestamp::try_scale`], but for signed storage (digits below the target unit are truncated toward zero)
+    #[inline]
+    pub fn try_scale_signed<const POWER_OUT: i32>(self) -> Result<Timestamp<i64, POWER_OUT>, TimestampTryScaleError> {
+        use TimestampTryScaleError::*;
+        scale_u128(u128::from(self.value.unsigned_abs()), i64::from(POWER) - i64::from(POWER_OUT))
+            .and_then(|magnitude| with_sign(self.value < 0, magnitude))
+            .and_then(|value| i64::try_from(value).ok())
+            .map(Timestamp::new)
+            .ok_or(SignedScaleFailed {
+                value: i128::from(self.value),
+                power_in: POWER,
+                power_out: POWER_OUT,
+                reason: ScaleFailureReason::new(POWER, POWER_OUT, MAX_POW10_U128),
+            })
+    }
+}
+
+impl<V: Copy + Into<u128> + TryFrom<u128>, const POWER: i32> Timestamp<V, POWER> {
+    /// Adds `duration` converted into `POWER` units (digits below the unit are truncated), or returns `None` on overflow
+    #[inline]
+    pub fn checked_add_duration(self, duration: Duration) -> Option<Self> {
+        duration_to_units::<POWER>(duration)
+            .and_then(|units| self.value.into().checked_add(units))
+            .and_then(|value| V::try_from(value).ok())
+            .map(Self::new)
+    }
+
+    /// Subtracts `duration` converted into `POWER` units (digits below the unit are truncated), or returns `None` on underflow
+    #[inline]
+    pub fn checked_sub_duration(self, duration: Duration) -> Option<Self> {
+        duration_to_units::<POWER>(duration)
+            .and_then(|units| self.value.into().checked_sub(units))
+            .and_then(|value| V::try_from(value).ok())
+            .map(Self::new)
+    }
+
+    /// Same as [`Self::checked_add_duration`], but advances `self` in place, leaving it unchanged on overflow
+    #[inline]
+    pub fn try_add_assign_duration(&mut self, duration: Duration) -> Result<(), OverflowError> {
+        *self = self
+            .checked_add_duration(duration)
+            .ok_or(OverflowError::Overflow)?;
+        Ok(())
+    }
+
+    /// Same as [`Self::checked_sub_duration`], but moves `self` back in place, leaving it unchanged on underflow
+    #[inline]
+    pub fn try_sub_assign_duration(&mut self, duration: Duration) -> Result<(), OverflowError> {
+        *self = self
+            .checked_sub_duration(duration)
+            .ok_or(OverflowError::Underflow)?;
+        Ok(())
+    }
+
+    /// Returns the timestamp that is `elapsed` after `base` (see [`Self::checked_add_duration`])
+    #[inline]
+    pub fn from_base_plus(base: Self, elapsed: Duration) -> Option<Self> {
+        base.checked_add_duration(elapsed)
+    }
+
+    /// Rounds the timestamp down to the nearest multiple of `resolution` since Unix epoch (e.g. to the start of the minute)
+    ///
+    /// Returns `None` if `resolution` is zero or is not a whole number of `POWER` units (e.g. 1.5s for [`TimestampSeconds`](crate::TimestampSeconds)), because most of its multiples can't be represented exactly.
+    #[inline]
+    pub fn quantize(self, resolution: Duration) -> Option<Self> {
+        let resolution_ns = resolution.as_nanos();
+        let value = self.value.into();
+        let quantized = match nanoseconds_to_timestamp_value(resolution_ns, POWER) {
+            Some(resolution_units) => {
+                let whole = resolution_units != 0 && timestamp_value_to_nanoseconds(resolution_units, POWER) == Some(resolution_ns);
+                whole.then(|| value - value % resolution_units)?
+            }
+            // the resolution exceeds `u128::MAX` units, so the only multiple that doesn't exceed the value is zero
+            None => 0,
+        };
+        V::try_from(quantized).ok().map(Self::new)
+    }
+
+    /// Clamps the value to the latest timestamp that fits into [`Duration`], so that [`Self::to_duration`] doesn't need to saturate
+    #[inline]
+    pub fn clamped_to_representable_duration(self) -> Self {
+        let value = self.value.into();
+        // `None` means that every value fits, because `Duration::MAX` exceeds `u128::MAX` units
+        duration_to_units::<POWER>(Duration::MAX)
+            .filter(|&max| value > max)
+            .and_then(|max| V::try_from(max).ok())
+            .map_or(self, Self::new)
+    }
 
-        if power == 0 {
-            return f.write_str(sign).and_then(|_| f.write_str(digits));
+    /// Returns the earliest instant that is strictly later than `self` and is a multiple of `period` since Unix epoch
+    ///
+    /// Returns `None` if `period` is zero, on overflow, or if the aligned instant can't be represented exactly in `POWER` units.
+    #[inline]
+    pub fn next_aligned(self, period: Duration) -> Option<Self> {
+        let period_ns = period.as_nanos();
+        if period_ns == 0 {
+            return None;
         }
+        let total_ns = timestamp_value_to_nanoseconds(self.value.into(), POWER)?;
+        let aligned_ns = (total_ns / period_ns)
+            .checked_add(1)?
+            .checked_mul(period_ns)?;
+        exact_nanoseconds_to_timestamp_value(aligned_ns, POWER)
+            .and_then(|value| V::try_from(value).ok())
+            .map(Self::new)
+    }
+
+    /// Converts an NTP timestamp (seconds since 1900 and a fraction of a second in units of `2^-32`), truncating the digits below the unit, or returns `None` for times before Unix epoch or on overflow
+    #[inline]
+    pub fn from_ntp(seconds_since_1900: u32, fraction: u32) -> Option<Self> {
+        let secs = u64::from(seconds_since_1900).checked_sub(NTP_UNIX_EPOCH_SECS)?;
+        let fixed = u128::from(secs) << 32 | u128::from(fraction);
+        scale_u128(fixed, -i64::from(POWER))
+            .map(|scaled| scaled >> 32)
+            .and_then(|value| V::try_from(value).ok())
+            .map(Self::new)
+    }
+}
+
+#[cfg(feature = "testing")]
+impl<V: Copy + PartialEq + Into<u128> + TryFrom<u128>, const POWER: i32> Timestamp<V, POWER> {
+    /// Returns `true` if scaling into `POWER_OUT` never truncates digits (it may still overflow)
+    #[inline]
+    pub const fn lossless_scale_possible<const POWER_OUT: i32>() -> bool {
+        POWER_OUT <= POWER
+    }
+
+    /// Returns `true` if scaling into `POWER_OUT` and back recovers `self`
+    #[inline]
+    pub fn debug_round_trip<const POWER_OUT: i32>(self) -> bool {
+        self.try_scale::<POWER_OUT>()
+            .and_then(Timestamp::try_scale::<POWER>)
+            .is_ok_and(|timestamp| timestamp == self)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TimestampTryScaleError {
+    ScaleFailed { value: u128, power_in: i32, power_out: i32, reason: ScaleFailureReason },
+    SignedScaleFailed { value: i128, power_in: i32, power_out: i32, reason: ScaleFailureReason },
+}
 
-        if power > 0 {
-            return f
-                .write_str(sign)
-                .and_then(|_| f.write_str(digits))
-                .and_then(|_| write_zeros(f, power as usize));
+impl fmt::Display for TimestampTryScaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TimestampTryScaleError::*;
+        match self {
+            ScaleFailed {
+                value,
+                power_in,
+                power_out,
+                reason,
+            } => write!(f, "failed to scale {value} from power {power_in} to power {power_out}: {reason}"),
+            SignedScaleFailed {
+                value,
+                power_in,
+                power_out,
+                reason,
+            } => write!(f, "failed to scale {value} from power {power_in} to power {power_out}: {reason}"),
         }
+    }
+}
 
-        let scale = (-power) as usize;
-        f.write_str(sign)?;
+impl core::error::Error for TimestampTryScaleError {}
+
+impl<V: Copy + Into<u128>, const POWER: i32> Timestamp<V, POWER> {
+    /// Returns the time since Unix epoch, or `None` if it exceeds [`Duration::MAX`] or has non-zero digits below a nanosecond (see [`Self::try_to_duration`] for the reason)
+    ///
+    /// The intermediate nanosecond count is a `u128`, which can hold any value up to [`Duration::MAX`], so `None` is never returned prematurely.
+    #[inline]
+    pub fn checked_to_duration(&self) -> Option<Duration> {
+        self.try_to_duration().ok()
+    }
 
-        if digits.len() > scale {
-            let split = digits.len() - scale;
-            let (int_part, frac_part) = digits.split_at(split);
-            return f
-                .write_str(int_part)
-                .and_then(|_| f.write_str("."))
-                .and_then(|_| f.write_str(frac_part));
+    /// Returns the time since Unix epoch, or an error if it exceeds [`Duration::MAX`] or has non-zero digits below a nanosecond (e.g. for `POWER = -12`)
+    #[inline]
+    pub fn try_to_duration(&self) -> Result<Duration, ToDurationError> {
+        use ToDurationError::*;
+        let value = self.value.into();
+        let total_ns = timestamp_value_to_nanoseconds(value, POWER).ok_or(TooLarge)?;
+        if nanoseconds_to_timestamp_value(total_ns, POWER) != Some(value) {
+            return Err(SubNanosecondDigits);
         }
+        checked_nanoseconds_to_duration(total_ns).ok_or(TooLarge)
+    }
 
-        f.write_str("0.")
-            .and_then(|_| write_zeros(f, scale.saturating_sub(digits.len())))
-            .and_then(|_| f.write_str(digits))
+    /// Returns the time since Unix epoch, saturating at [`Duration::MAX`], or `None` if it has non-zero digits below a nanosecond
+    ///
+    /// Large positive powers saturate quickly: [`Duration::MAX`] is about `1.8 * 10^19` seconds, so e.g. `POWER = 9` saturates for values above about `1.8 * 10^10`. Use [`Self::try_to_duration`] to detect the saturation.
+    #[inline]
+    pub fn to_duration(&self) -> Option<Duration> {
+        use ToDurationError::*;
+        match self.try_to_duration() {
+            Ok(duration) => Some(duration),
+            Err(TooLarge) => Some(Duration::MAX),
+            Err(SubNanosecondDigits) => None,
+        }
+    }
+
+    /// Same as [`Self::to_duration`], but with a name that makes the saturation explicit at the call site
+    #[inline]
+    pub fn to_duration_saturating(&self) -> Option<Duration> {
+        self.to_duration()
+    }
+
+    /// Returns the whole seconds since Unix epoch (the digits below a second are dropped), saturating at `u64::MAX`
+    #[inline]
+    pub fn unix_secs(&self) -> u64 {
+        self.seconds_and_subsecond_units().0
+    }
+
+    /// Splits the timestamp at the second boundary into the whole seconds since Unix epoch (saturating at `u64::MAX`) and the remainder in `POWER` units (e.g. `(1, 500)` for `1500ms`)
+    #[inline]
+    pub fn seconds_and_subsecond_units(&self) -> (u64, u128) {
+        let value = self.value.into();
+        let (secs, remainder) = if POWER >= 0 {
+            (scale_u128(value, i64::from(POWER)).unwrap_or(u128::MAX), 0)
+        } else {
+            match pow10_u128(POWER.unsigned_abs()) {
+                Some(factor) => (value / factor, value % factor),
+                None => (0, value),
+            }
+        };
+        (u64::try_from(secs).unwrap_or(u64::MAX), remainder)
+    }
+
+    /// Returns the whole milliseconds since Unix epoch, saturating at `u128::MAX`
+    #[inline]
+    pub fn as_unix_millis(&self) -> u128 {
+        scale_u128(self.value.into(), i64::from(POWER) - i64::from(MILLI)).unwrap_or(u128::MAX)
+    }
+
+    /// Returns `true` if the timestamps differ by at most `tolerance` (e.g. to tolerate clock skew)
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, tolerance: Duration) -> bool {
+        let diff = self.value.into().abs_diff(other.value.into());
+        // flooring the tolerance is exact because `diff` is a whole number of units
+        duration_to_units::<POWER>(tolerance).is_none_or(|tolerance| diff <= tolerance)
+    }
+
+    /// Returns [`Ordering::Equal`](core::cmp::Ordering::Equal) if the timestamps are [approximately equal](Self::approx_eq), otherwise compares them exactly
+    ///
+    /// Note that this relation is not transitive, so it is not a total order: sorting with it may produce an unspecified order (or panic) when near-equal timestamps form chains longer than `tolerance`.
+    #[inline]
+    pub fn cmp_approx(&self, other: &Self, tolerance: Duration) -> core::cmp::Ordering {
+        if self.approx_eq(other, tolerance) {
+            core::cmp::Ordering::Equal
+        } else {
+            self.value.into().cmp(&other.value.into())
+        }
+    }
+
+    /// Returns the time since the latest multiple of `period` since Unix epoch (e.g. the time since the top of the hour), or `None` if `period` is zero or the remainder has non-zero digits below a nanosecond
+    ///
+    /// The remainder is computed without converting the whole timestamp into nanoseconds, so it is correct for every value.
+    #[inline]
+    pub fn checked_rem_duration(&self, period: Duration) -> Option<Duration> {
+        let period_ns = period.as_nanos();
+        if period_ns == 0 {
+            return None;
+        }
+        let value = self.value.into();
+        let remainder_ns = match exact_nanoseconds_to_timestamp_value(period_ns, POWER) {
+            // the remainder is below the period, so it fits into nanoseconds
+            Some(period_units) => exact_timestamp_value_to_nanoseconds(value % period_units, POWER)?,
+            // the period is finer than the unit, so the remainder is computed modulo the period in nanoseconds
+            None if POWER >= NANO => mul_rem_u128(value, pow10_rem_u128((i64::from(POWER) - i64::from(NANO)) as u32, period_ns), period_ns),
+            // the period overflows `u128` units, so it exceeds the timestamp
+            None => exact_timestamp_value_to_nanoseconds(value, POWER)?,
+        };
+        Some(nanoseconds_to_duration(remainder_ns))
+    }
+
+    /// Multiplies the value by `num / den` (e.g. `30 / 1` to convert seconds into video frames), truncating the result, or returns `None` if `den` is zero or on overflow
+    #[inline]
+    pub fn scale_by_ratio(self, num: u128, den: u128) -> Option<Timestamp<u128, POWER>> {
+        mul_div_u128(self.value.into(), num, den).map(Timestamp::new)
+    }
+
+    /// Converts the timestamp into an NTP timestamp (seconds since 1900 and a fraction of a second in units of `2^-32`), or returns `None` after the NTP era rollover in 2036
+    ///
+    /// The fraction is rounded up, so that [`Self::from_ntp`] recovers the timestamp for powers down to nanoseconds (one NTP fraction unit is about 0.23 nanoseconds).
+    #[inline]
+    pub fn to_ntp(&self) -> Option<(u32, u32)> {
+        let value = self.value.into();
+        let (secs, remainder, scale) = match u64::try_from(-i64::from(POWER)) {
+            Err(_) => (scale_u128(value, i64::from(POWER))?, 0, 0),
+            Ok(scale) => match pow10_u128(scale as u32) {
+                Some(factor) => (value / factor, value % factor, scale),
+                None => (0, value, scale),
+            },
+        };
+        let seconds_since_1900 = u32::try_from(secs.checked_add(u128::from(NTP_UNIX_EPOCH_SECS))?).ok()?;
+        // keep at most 19 fractional digits, so that shifting the remainder by 32 bits can't overflow
+        let remainder = scale_u128(remainder, -(scale.saturating_sub(MAX_POW10_U64) as i64))?;
+        let factor = pow10_u128(scale.min(MAX_POW10_U64) as u32)?;
+        let fraction = u32::try_from((remainder << 32).div_ceil(factor)).unwrap_or(u32::MAX);
+        Some((seconds_since_1900, fraction))
+    }
+
+    /// Returns the number of whole `period` intervals between `self` and `other` (e.g. billable 15-minute slots), or `None` if `period` is zero, `other` is earlier than `self`, or the gap exceeds `u128::MAX` nanoseconds while `period` is not a whole number of `POWER` units
+    ///
+    /// The gap is never truncated: it is divided in `POWER` units if `period` is a whole number of them, and in nanoseconds (which are then a whole number of `POWER` units) otherwise.
+    #[inline]
+    pub fn intervals_between(self, other: Self, period: Duration) -> Option<u128> {
+        let diff = other.value.into().checked_sub(self.value.into())?;
+        let period_ns = period.as_nanos();
+        match exact_nanoseconds_to_timestamp_value(period_ns, POWER) {
+            Some(period_units) => diff.checked_div(period_units),
+            // the period overflows `u128` units, so it exceeds the gap
+            None if POWER < NANO => Some(0),
+            None => timestamp_value_to_nanoseconds(diff, POWER).and_then(|diff_ns| diff_ns.checked_div(period_ns)),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ToDurationError {
+    TooLarge,
+    SubNanosecondDigits,
+}
+
+impl fmt::Display for ToDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ToDurationError::*;
+        match self {
+            TooLarge => f.write_str("timestamp exceeds Duration::MAX"),
+            SubNanosecondDigits => f.write_str("timestamp has non-zero digits below a nanosecond, which Duration can't represent"),
+        }
+    }
+}
+
+impl core::error::Error for ToDurationError {}
+
+/// Renders `Timestamp { value: 1500 }`, or `Timestamp { value: 1500, power: -3, rendered: "1.500" }` in the alternate form (`{:#?}`)
+impl<V: fmt::Debug, const POWER: i32> fmt::Debug for Timestamp<V, POWER> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let alternate = f.alternate();
+        let mut debug = f.debug_struct("Timestamp");
+        debug.field("value", &self.value);
+        if alternate {
+            debug
+                .field("power", &POWER)
+                .field("rendered", &RenderedSeconds::new(self));
+        }
+        debug.finish()
     }
 }
 
@@ -76,6 +557,20 @@ impl<V: fmt::Display, const POWER: i32> fmt::Display for Timestamp<V, POWER> {
     }
 }
 
+macro_rules! impl_fmt_passthrough {
+    ($($trait:ident),+ $(,)?) => {
+        $(
+            impl<V: fmt::$trait, const POWER: i32> fmt::$trait for Timestamp<V, POWER> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.value.fmt(f)
+                }
+            }
+        )+
+    };
+}
+
+impl_fmt_passthrough!(Binary, Octal, LowerHex, UpperHex);
+
 impl<V, const POWER: i32> From<V> for Timestamp<V, POWER> {
     #[inline]
     fn from(value: V) -> Self {
@@ -122,6 +617,39 @@ pub const MILLI: i32 = -3;
 pub const MICRO: i32 = -6;
 pub const NANO: i32 = -9;
 
+/// Unlike the finer aliases, uses `u64` storage, so [`Timestamp::try_scale`] into them keeps `u64` (convert with `From`/`TryFrom` or [`Timestamp::try_convert_storage`] to get the aliases directly)
+///
+/// The storage stays `u64` on purpose: it is the type of [`Duration::as_secs`](core::time::Duration::as_secs), so it already covers every [`Duration`](core::time::Duration) and `u128` would add no reachable range. The finer aliases need `u128` because `u64` nanoseconds overflow in 2554.
+///
+/// The conversions from this alias into the finer aliases are lossless and infallible. The conversions between the finer aliases and back into this alias fail if the value overflows or has non-zero digits below the target unit.
+pub type TimestampSeconds = Timestamp<u64, UNO>;
+pub type TimestampMilliseconds = Timestamp<u128, MILLI>;
+pub type TimestampMicroseconds = Timestamp<u128, MICRO>;
+pub type TimestampNanoseconds = Timestamp<u128, NANO>;
+
+/// Unit-named constructors: unlike [`Timestamp::new`], the return type fixes the power (e.g. `Timestamp::seconds(5)` is a [`TimestampSeconds`])
+impl Timestamp {
+    #[inline]
+    pub const fn seconds(value: u64) -> TimestampSeconds {
+        TimestampSeconds::new(value)
+    }
+
+    #[inline]
+    pub const fn millis(value: u128) -> TimestampMilliseconds {
+        TimestampMilliseconds::new(value)
+    }
+
+    #[inline]
+    pub const fn micros(value: u128) -> TimestampMicroseconds {
+        TimestampMicroseconds::new(value)
+    }
+
+    #[inline]
+    pub const fn nanos(value: u128) -> TimestampNanoseconds {
+        TimestampNanoseconds::new(value)
+    }
+}
+
 impl From<Duration> for Timestamp<u64, UNO> {
     #[inline]
     fn from(duration: Duration) -> Self {
@@ -157,104 +685,1849 @@ impl From<Duration> for Timestamp<u128, NANO> {
     }
 }
 
-impl From<Timestamp<u64, NANO>> for Duration {
-    #[inline]
-    fn from(timestamp: Timestamp<u64, NANO>) -> Self {
-        Duration::from_nanos(timestamp.value)
-    }
+macro_rules! impl_from_narrower_storage {
+    ($($from:ty => $into:ty),+ $(,)?) => {
+        $(
+            /// Widens the storage type, keeping the power
+            ///
+            /// ```
+            /// use timestamp_please::Timestamp;
+            ///
+            #[doc = concat!("let timestamp: Timestamp<", stringify!($into), ", -3> = Timestamp::<", stringify!($from), ", -3>::new(1_500).into();")]
+            /// assert_eq!(timestamp.value(), 1_500);
+            /// ```
+            impl<const POWER: i32> From<Timestamp<$from, POWER>> for Timestamp<$into, POWER> {
+                #[inline]
+                fn from(timestamp: Timestamp<$from, POWER>) -> Self {
+                    Self::new(<$into>::from(timestamp.value))
+                }
+            }
+        )+
+    };
 }
 
-// `impl From<Timestamp<u128, NANO>> for Duration` is not implementable because `Duration::from_nanos` accepts only `u64`
+impl_from_narrower_storage!(
+    u32 => u64,
+    u32 => u128,
+    u64 => u128,
+    i32 => i64,
+    i32 => i128,
+    i64 => i128,
+    u32 => i64,
+    u32 => i128,
+    u64 => i128,
+);
 
-#[inline]
-#[doc(hidden)]
-pub fn write_zeros(f: &mut impl fmt::Write, count: usize) -> fmt::Result {
-    core::iter::repeat_n("0", count).try_for_each(|zero| f.write_str(zero))
+macro_rules! impl_from_coarser_unit {
+    ($($from:ty, $power_from:ident => $into:ty, $power_into:ident);+ $(;)?) => {
+        $(
+            /// Converts into a finer unit with wider storage, which can hold any scaled value
+            impl From<Timestamp<$from, $power_from>> for Timestamp<$into, $power_into> {
+                #[inline]
+                fn from(timestamp: Timestamp<$from, $power_from>) -> Self {
+                    const FACTOR: $into = (10 as $into).pow(($power_from - $power_into) as u32);
+                    Self::new(<$into>::from(timestamp.value) * FACTOR)
+                }
+            }
+        )+
+    };
 }
 
-#[inline]
-pub fn pow10_u128(exp: u32) -> Option<u128> {
-    if u64::from(exp) > MAX_POW10_U128 {
-        return None;
-    }
+// `$into::MAX / $from::MAX` exceeds the factor for every pair, so the multiplication can't overflow
+impl_from_coarser_unit!(
+    u32, UNO => u64, MILLI;
+    u32, MILLI => u64, MICRO;
+    u32, MICRO => u64, NANO;
+    u64, UNO => u128, MILLI;
+    u64, MILLI => u128, MICRO;
+    u64, MICRO => u128, NANO;
+    u64, UNO => u128, MICRO;
+    u64, UNO => u128, NANO;
+    i32, UNO => i64, MILLI;
+    i32, MILLI => i64, MICRO;
+    i32, MICRO => i64, NANO;
+    i64, UNO => i128, MILLI;
+    i64, MILLI => i128, MICRO;
+    i64, MICRO => i128, NANO;
+);
 
-    core::iter::repeat_n(10u128, exp as usize).try_fold(1u128, |acc, value| acc.checked_mul(value))
+macro_rules! impl_try_from_other_unit {
+    ($($from:ty, $power_from:ident => $into:ty, $power_into:ident);+ $(;)?) => {
+        $(
+            /// Rejects the values that overflow the target storage or have non-zero digits below the target unit
+            impl TryFrom<Timestamp<$from, $power_from>> for Timestamp<$into, $power_into> {
+                type Error = TimestampTryScaleError;
+
+                #[inline]
+                fn try_from(timestamp: Timestamp<$from, $power_from>) -> Result<Self, Self::Error> {
+                    use ScaleFailureReason::*;
+                    let value = u128::from(timestamp.value);
+                    let exp = i64::from($power_from) - i64::from($power_into);
+                    let result = match scale_u128(value, exp) {
+                        Some(scaled) if scale_u128(scaled, -exp) != Some(value) => Err(Inexact),
+                        Some(scaled) => <$into>::try_from(scaled).map_err(|_| ValueTooLarge),
+                        None => Err(ScaleFailureReason::new($power_from, $power_into, MAX_POW10_U128)),
+                    };
+                    result.map(Self::new).map_err(|reason| TimestampTryScaleError::ScaleFailed {
+                        value,
+                        power_in: $power_from,
+                        power_out: $power_into,
+                        reason,
+                    })
+                }
+            }
+        )+
+    };
 }
 
-#[cfg(feature = "std")]
-mod interop_std {
-    use super::*;
+// the lossless conversions between the aliases are implemented via `From` above
+impl_try_from_other_unit!(
+    u128, MILLI => u128, MICRO;
+    u128, MILLI => u128, NANO;
+    u128, MICRO => u128, NANO;
+    u128, MICRO => u128, MILLI;
+    u128, NANO => u128, MILLI;
+    u128, NANO => u128, MICRO;
+    u128, MILLI => u64, UNO;
+    u128, MICRO => u64, UNO;
+    u128, NANO => u64, UNO;
+);
 
-    macro_rules! impl_try_from_system_time {
-        ($target:ty) => {
-            impl TryFrom<std::time::SystemTime> for $target {
-                type Error = std::time::SystemTimeError;
+macro_rules! impl_try_from_other_storage {
+    ($($from:ty => $into:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> TryFrom<Timestamp<$from, POWER>> for Timestamp<$into, POWER> {
+                type Error = core::num::TryFromIntError;
 
                 #[inline]
-                fn try_from(system_time: std::time::SystemTime) -> Result<Self, Self::Error> {
-                    let duration = system_time.duration_since(std::time::UNIX_EPOCH)?;
-                    Ok(Self::from(duration))
+                fn try_from(timestamp: Timestamp<$from, POWER>) -> Result<Self, Self::Error> {
+                    <$into>::try_from(timestamp.value).map(Self::new)
                 }
             }
-        };
-    }
+        )+
+    };
+}
+
+impl_try_from_other_storage!(
+    u32 => i32,
+    i32 => u32,
+    i32 => u64,
+    i32 => u128,
+    u64 => u32,
+    u64 => i32,
+    u64 => i64,
+    i64 => u32,
+    i64 => i32,
+    i64 => u64,
+    i64 => u128,
+    u128 => u32,
+    u128 => i32,
+    u128 => u64,
+    u128 => i64,
+    u128 => i128,
+    i128 => u32,
+    i128 => i32,
+    i128 => u64,
+    i128 => i64,
+    i128 => u128,
+);
 
-    impl_try_from_system_time!(Timestamp<u64, UNO>);
-    impl_try_from_system_time!(Timestamp<u128, MILLI>);
-    impl_try_from_system_time!(Timestamp<u128, MICRO>);
-    impl_try_from_system_time!(Timestamp<u128, NANO>);
+macro_rules! impl_saturating_from_duration {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Converts the time since Unix epoch into `POWER` units, truncating the digits below the unit and clamping to the max value of the storage type
+                #[inline]
+                pub fn saturating_from_duration(duration: Duration) -> Self {
+                    let value = duration_to_units::<POWER>(duration)
+                        .and_then(|value| <$value>::try_from(value).ok())
+                        .unwrap_or(<$value>::MAX);
+                    Self::new(value)
+                }
+            }
+        )+
+    };
 }
 
-#[cfg(feature = "time")]
-mod interop_time {
-    use super::*;
-    use time::OffsetDateTime;
-    use time::error::ComponentRange;
+impl_saturating_from_duration!(u32, i32, u64, i64, u128, i128);
 
-    impl From<OffsetDateTime> for Timestamp<i128, NANO> {
-        #[inline]
-        fn from(dt: OffsetDateTime) -> Self {
-            Timestamp::new(dt.unix_timestamp_nanos())
-        }
+impl From<Timestamp<u64, NANO>> for Duration {
+    #[inline]
+    fn from(timestamp: Timestamp<u64, NANO>) -> Self {
+        Duration::from_nanos(timestamp.value)
     }
+}
 
-    impl TryFrom<Timestamp<i128, NANO>> for OffsetDateTime {
-        type Error = ComponentRange;
+// `impl From<Timestamp<u128, NANO>> for Duration` is not implementable because `Duration::from_nanos` accepts only `u64`
 
-        #[inline]
-        fn try_from(timestamp: Timestamp<i128, NANO>) -> Result<Self, Self::Error> {
-            OffsetDateTime::from_unix_timestamp_nanos(timestamp.value)
-        }
+/// Compares the time since Unix epoch exactly (not the raw value), so a timestamp that doesn't fit into `Duration` or has non-zero digits below a nanosecond is never equal to any `Duration` (see [`Timestamp::try_to_duration`])
+impl<V: Copy + Into<u128>, const POWER: i32> PartialEq<Duration> for Timestamp<V, POWER> {
+    #[inline]
+    fn eq(&self, other: &Duration) -> bool {
+        self.try_to_duration()
+            .is_ok_and(|duration| duration == *other)
     }
 }
 
-#[cfg(feature = "chrono")]
-mod interop_chrono {
-    use super::*;
-    use chrono::{DateTime, TimeZone, Utc};
-
-    impl<Tz: TimeZone> TryFrom<DateTime<Tz>> for Timestamp<i128, NANO> {
-        type Error = UnrepresentableChronoDateTimeError;
+/// Returns the time since the latest multiple of `rhs` since Unix epoch (see [`Timestamp::checked_rem_duration`])
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero (like the integer `%`), or if the remainder has non-zero digits below a nanosecond (which `Duration` can't represent).
+impl<V: Copy + Into<u128>, const POWER: i32> Rem<Duration> for Timestamp<V, POWER> {
+    type Output = Duration;
 
-        #[inline]
-        fn try_from(dt: DateTime<Tz>) -> Result<Self, Self::Error> {
-            dt.timestamp_nanos_opt()
-                .map(i128::from)
+    #[inline]
+    fn rem(self, rhs: Duration) -> Duration {
+        match self.checked_rem_duration(rhs) {
+            Some(remainder) => remainder,
+            None => panic!("attempt to calculate the remainder with a zero duration, or the remainder has digits below a nanosecond"),
+        }
+    }
+}
+
+/// Advances the timestamp by `rhs` (see [`Timestamp::try_add_assign_duration`])
+///
+/// # Panics
+///
+/// Panics on overflow.
+impl<V: Copy + Into<u128> + TryFrom<u128>, const POWER: i32> AddAssign<Duration> for Timestamp<V, POWER> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Duration) {
+        if self.try_add_assign_duration(rhs).is_err() {
+            panic!("attempt to add a duration with overflow")
+        }
+    }
+}
+
+/// Moves the timestamp back by `rhs` (see [`Timestamp::try_sub_assign_duration`])
+///
+/// # Panics
+///
+/// Panics on underflow.
+impl<V: Copy + Into<u128> + TryFrom<u128>, const POWER: i32> SubAssign<Duration> for Timestamp<V, POWER> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Duration) {
+        if self.try_sub_assign_duration(rhs).is_err() {
+            panic!("attempt to subtract a duration with overflow")
+        }
+    }
+}
+
+macro_rules! impl_partial_eq_value {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            /// Compares the raw stored units (not the instants, so it doesn't account for the power)
+            impl<const POWER: i32> PartialEq<$value> for Timestamp<$value, POWER> {
+                #[inline]
+                fn eq(&self, other: &$value) -> bool {
+                    self.value == *other
+                }
+            }
+        )+
+    };
+}
+
+impl_partial_eq_value!(u32, i32, u64, i64, u128, i128);
+
+macro_rules! impl_saturating_add {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            /// Adds the raw values with saturation, treating `rhs` as an offset from `self` (adding two absolute instants has no physical meaning)
+            impl<const POWER: i32> Add for Timestamp<$value, POWER> {
+                type Output = Self;
+
+                #[inline]
+                fn add(self, rhs: Self) -> Self {
+                    Self::new(self.value.saturating_add(rhs.value))
+                }
+            }
+
+            impl<const POWER: i32> AddAssign for Timestamp<$value, POWER> {
+                #[inline]
+                fn add_assign(&mut self, rhs: Self) {
+                    *self = *self + rhs;
+                }
+            }
+        )+
+    };
+}
+
+impl_saturating_add!(u32, i32, u64, i64, u128, i128);
+
+macro_rules! impl_try_add {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Adds an offset expressed in `POWER` units, or returns `None` on overflow (unlike [`Duration`], the offset can be finer than a nanosecond)
+                #[inline]
+                pub fn checked_add_same_unit(self, units: Self) -> Option<Self> {
+                    self.value.checked_add(units.value).map(Self::new)
+                }
+
+                /// Same as [`Add`], but returns an error instead of saturating
+                #[inline]
+                pub fn try_add(self, other: Self) -> Result<Self, OverflowError> {
+                    self.value.checked_add(other.value).map(Self::new).ok_or(if other.value < 0 as $value { OverflowError::Underflow } else { OverflowError::Overflow })
+                }
+
+                /// Subtracts the raw values, returning an error if the result doesn't fit into the storage type (e.g. below zero for unsigned storage)
+                #[inline]
+                pub fn try_sub(self, other: Self) -> Result<Self, OverflowError> {
+                    self.value.checked_sub(other.value).map(Self::new).ok_or(if other.value < 0 as $value { OverflowError::Overflow } else { OverflowError::Underflow })
+                }
+            }
+        )+
+    };
+}
+
+impl_try_add!(u32, i32, u64, i64, u128, i128);
+
+macro_rules! impl_bump_monotonic {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Advances `self` to `now`, or by one unit if `now` isn't later (e.g. for a hybrid logical clock), saturating at the maximum value
+                #[inline]
+                pub fn bump_monotonic(&mut self, now: Self) {
+                    self.value = self.value.saturating_add(1).max(now.value);
+                }
+            }
+        )+
+    };
+}
+
+impl_bump_monotonic!(u32, i32, u64, i64, u128, i128);
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum OverflowError {
+    Overflow,
+    Underflow,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use OverflowError::*;
+        match self {
+            Overflow => f.write_str("timestamp value overflowed the storage type"),
+            Underflow => f.write_str("timestamp value underflowed the storage type"),
+        }
+    }
+}
+
+impl core::error::Error for OverflowError {}
+
+impl<const POWER: i32> Timestamp<u128, POWER> {
+    /// Linearly interpolates between `self` (at `t = 0`) and `other` (at `t = 1`), rounding to the nearest unit (halves are rounded toward the later timestamp, so `a.lerp(b, t) == b.lerp(a, 1.0 - t)` whenever `1.0 - t` is exact)
+    ///
+    /// `t` is clamped to `[0, 1]` (`NaN` is treated as `0`). The offset is computed in `f64`, so it may lose precision for gaps above 2^53 units.
+    #[inline]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        if t >= 1.0 {
+            return other;
+        }
+        if t.is_nan() || t <= 0.0 {
+            return self;
+        }
+        // measuring the position from the earlier timestamp makes the rounding independent of the argument order
+        let (earlier, diff, t) = if self.value <= other.value {
+            (self.value, other.value - self.value, t)
+        } else {
+            (other.value, self.value - other.value, 1.0 - t)
+        };
+        // `as` saturates on overflow
+        let offset = ((diff as f64) * t + 0.5) as u128;
+        Self::new(earlier + offset.min(diff))
+    }
+}
+
+macro_rules! impl_signed_sub {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Returns `self - rhs` in `POWER` units (negative if `self` is earlier), or `None` on overflow
+                #[inline]
+                pub fn checked_signed_diff(self, rhs: Self) -> Option<$value> {
+                    self.value.checked_sub(rhs.value)
+                }
+            }
+
+            /// Returns the signed difference in `POWER` units (see [`Timestamp::checked_signed_diff`])
+            ///
+            /// # Panics
+            ///
+            /// Panics on overflow.
+            impl<const POWER: i32> Sub for Timestamp<$value, POWER> {
+                type Output = $value;
+
+                #[inline]
+                fn sub(self, rhs: Self) -> $value {
+                    match self.checked_signed_diff(rhs) {
+                        Some(diff) => diff,
+                        None => panic!("attempt to subtract with overflow"),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_signed_sub!(i32, i64, i128);
+
+macro_rules! impl_checked_offset {
+    ($($value:ty => $delta:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Adds a signed number of `POWER` units (e.g. `-1` for a leap second correction), or returns `None` on overflow or underflow below zero
+                #[inline]
+                pub fn checked_offset(self, delta: $delta) -> Option<Self> {
+                    self.value.checked_add_signed(delta).map(Self::new)
+                }
+            }
+        )+
+    };
+}
+
+impl_checked_offset!(u32 => i32, u64 => i64, u128 => i128);
+
+macro_rules! impl_diff_units {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Returns the absolute difference in `POWER` units (cheaper than converting to `Duration`; always fits into `u128`)
+                #[inline]
+                pub fn diff_units(self, other: Self) -> u128 {
+                    u128::from(self.value.abs_diff(other.value))
+                }
+            }
+        )+
+    };
+}
+
+impl_diff_units!(u32, i32, u64, i64, u128, i128);
+
+macro_rules! impl_div_units {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            /// Divides the raw value (treating it as a count of units), truncating the result
+            ///
+            /// # Panics
+            ///
+            /// Panics if `rhs` is zero (like the integer `/`).
+            impl<const POWER: i32> Div<$value> for Timestamp<$value, POWER> {
+                type Output = Self;
+
+                #[inline]
+                fn div(self, rhs: $value) -> Self {
+                    Self::new(self.value / rhs)
+                }
+            }
+        )+
+    };
+}
+
+impl_div_units!(u32, u64, u128);
+
+macro_rules! impl_epoch_predicates {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Returns `true` if the timestamp is earlier than Unix epoch (i.e. the value is negative)
+                #[inline]
+                pub const fn is_before_epoch(&self) -> bool {
+                    self.value < 0
+                }
+
+                /// Returns `true` if the timestamp is exactly Unix epoch
+                #[inline]
+                pub const fn is_epoch(&self) -> bool {
+                    self.value == 0
+                }
+
+                /// Returns `true` if the timestamp is later than Unix epoch (i.e. the value is positive)
+                #[inline]
+                pub const fn is_after_epoch(&self) -> bool {
+                    self.value > 0
+                }
+            }
+        )+
+    };
+}
+
+impl_epoch_predicates!(i32, i64, i128);
+
+macro_rules! impl_rem_units {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Returns the value modulo `period_units` (e.g. the seconds into the current minute for `period_units = 60` at `UNO` power)
+                ///
+                /// # Panics
+                ///
+                /// Panics if `period_units` is zero.
+                #[inline]
+                pub fn rem_units(self, period_units: $value) -> $value {
+                    self.value % period_units
+                }
+
+                /// Same as [`Self::rem_units`], but returns `None` if `period_units` is zero
+                #[inline]
+                pub fn checked_rem_units(self, period_units: $value) -> Option<$value> {
+                    self.value.checked_rem(period_units)
+                }
+            }
+        )+
+    };
+}
+
+impl_rem_units!(u32, u64, u128);
+
+macro_rules! impl_varint {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Writes the value into `buf` in the LEB128 varint encoding, returning the number of bytes written, or `None` if `buf` is too short
+                #[inline]
+                pub fn encode_varint(&self, buf: &mut [u8]) -> Option<usize> {
+                    let mut value = self.value;
+                    let mut len = 0;
+                    loop {
+                        let byte = (value & 0x7f) as u8;
+                        value >>= 7;
+                        let out = buf.get_mut(len)?;
+                        len += 1;
+                        if value == 0 {
+                            *out = byte;
+                            return Some(len);
+                        }
+                        *out = byte | 0x80;
+                    }
+                }
+
+                /// Reads a LEB128 varint from the start of `buf`, returning the timestamp and the number of bytes read, or `None` if `buf` is truncated or the value doesn't fit into the storage type
+                #[inline]
+                pub fn decode_varint(buf: &[u8]) -> Option<(Self, usize)> {
+                    use core::ops::ControlFlow::*;
+                    let max_len = (<$value>::BITS as usize).div_ceil(7);
+                    let decoded = buf
+                        .iter()
+                        .take(max_len)
+                        .enumerate()
+                        .try_fold(0, |value: $value, (index, &byte)| {
+                            let shift = index as u32 * 7;
+                            let chunk = <$value>::from(byte & 0x7f);
+                            if (chunk << shift) >> shift != chunk {
+                                // the chunk has bits beyond the storage type
+                                return Break(None);
+                            }
+                            let value = value | chunk << shift;
+                            if byte & 0x80 == 0 { Break(Some((Self::new(value), index + 1))) } else { Continue(value) }
+                        });
+                    match decoded {
+                        Break(decoded) => decoded,
+                        // `buf` ended (or reached the maximum length) without a terminating byte
+                        Continue(_) => None,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_varint!(u32, u64, u128);
+
+macro_rules! impl_from_str_radix {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Parses the raw value in the given `radix` (e.g. `16` for hex), unlike a fixed-point decimal string in seconds
+                #[inline]
+                pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, FromStrRadixError> {
+                    use FromStrRadixError::*;
+                    if !(2..=16).contains(&radix) {
+                        return Err(InvalidRadix {
+                            radix,
+                        });
+                    }
+                    <$value>::from_str_radix(s, radix).map(Self::new).map_err(|source| InvalidValue {
+                        source,
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_from_str_radix!(u32, i32, u64, i64, u128, i128);
+
+macro_rules! impl_wrapping_scale {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Scales the value into `POWER_OUT` units modulo `2^BITS` of the storage type (e.g. `2^64` for `u64`), truncating the digits below the target unit
+                #[inline]
+                pub fn wrapping_scale<const POWER_OUT: i32>(self) -> Timestamp<$value, POWER_OUT> {
+                    let exp = i64::from(POWER) - i64::from(POWER_OUT);
+                    let value = match u32::try_from(exp.unsigned_abs()) {
+                        // `10^exp` is divisible by `2^BITS` for `exp >= BITS`, so the product wraps to zero
+                        Ok(exp_abs) if exp >= 0 && exp_abs < <$value>::BITS => self.value.wrapping_mul((10 as $value).wrapping_pow(exp_abs)),
+                        // the quotient doesn't exceed the value, so it fits into the storage type
+                        Ok(exp_abs) if exp < 0 => pow10_u128(exp_abs).map_or(0, |factor| (u128::from(self.value) / factor) as $value),
+                        _ => 0,
+                    };
+                    Timestamp::new(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_wrapping_scale!(u32, u64, u128);
+
+macro_rules! impl_convert_with_policy {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Converts the value into `POWER_OUT` units (digits below the target unit are truncated), handling the overflow according to `policy` (only [`OverflowPolicy::Error`] returns an error)
+                #[inline]
+                pub fn convert_with_policy<const POWER_OUT: i32>(self, policy: OverflowPolicy) -> Result<Timestamp<$value, POWER_OUT>, TimestampTryScaleError> {
+                    use OverflowPolicy::*;
+                    match policy {
+                        Error => self.try_scale(),
+                        Saturate => Ok(self.try_scale().unwrap_or(Timestamp::new(<$value>::MAX))),
+                        Wrap => Ok(self.wrapping_scale()),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_convert_with_policy!(u32, u64, u128);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FromStrRadixError {
+    InvalidRadix { radix: u32 },
+    InvalidValue { source: core::num::ParseIntError },
+}
+
+impl fmt::Display for FromStrRadixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use FromStrRadixError::*;
+        match self {
+            InvalidRadix {
+                radix,
+            } => write!(f, "radix must be in 2..=16, got {radix}"),
+            InvalidValue {
+                source,
+            } => write!(f, "invalid raw timestamp value: {source}"),
+        }
+    }
+}
+
+impl core::error::Error for FromStrRadixError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        use FromStrRadixError::*;
+        match self {
+            InvalidRadix {
+                ..
+            } => None,
+            InvalidValue {
+                source,
+            } => Some(source),
+        }
+    }
+}
+
+macro_rules! impl_as_unit_f64 {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Returns the number of seconds since Unix epoch as `f64` (may lose precision).
+                #[inline]
+                pub fn as_secs_f64(&self) -> f64 {
+                    scale_f64(self.value as f64, i64::from(POWER) - i64::from(UNO))
+                }
+
+                /// Returns the number of milliseconds since Unix epoch as `f64` (may lose precision).
+                #[inline]
+                pub fn as_millis_f64(&self) -> f64 {
+                    scale_f64(self.value as f64, i64::from(POWER) - i64::from(MILLI))
+                }
+
+                /// Returns the number of microseconds since Unix epoch as `f64` (may lose precision).
+                #[inline]
+                pub fn as_micros_f64(&self) -> f64 {
+                    scale_f64(self.value as f64, i64::from(POWER) - i64::from(MICRO))
+                }
+
+                /// Returns the number of nanoseconds since Unix epoch as `f64` (may lose precision).
+                #[inline]
+                pub fn as_nanos_f64(&self) -> f64 {
+                    scale_f64(self.value as f64, i64::from(POWER) - i64::from(NANO))
+                }
+            }
+        )+
+    };
+}
+
+impl_as_unit_f64!(u32, i32, u64, i64, u128, i128);
+
+macro_rules! impl_unix_secs_i64 {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Returns the whole seconds since Unix epoch as `i64` (e.g. for a signed 64-bit database column), truncating toward zero, or `None` on overflow
+                #[inline]
+                pub fn to_unix_secs_i64(&self) -> Option<i64> {
+                    let value = i128::try_from(self.value).ok()?;
+                    scale_u128(value.unsigned_abs(), i64::from(POWER) - i64::from(UNO))
+                        .and_then(|magnitude| with_sign(value < 0, magnitude))
+                        .and_then(|secs| i64::try_from(secs).ok())
+                }
+            }
+        )+
+    };
+}
+
+impl_unix_secs_i64!(u32, i32, u64, i64, u128, i128);
+
+macro_rules! impl_unix_days {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Returns the whole days since Unix epoch, rounding toward negative infinity (e.g. `-1` for one second before epoch) and saturating at the `i64` bounds
+                #[inline]
+                pub fn unix_days(&self) -> i64 {
+                    // only `u128` values above `i128::MAX` fail here, and they saturate anyway
+                    let value = i128::try_from(self.value).unwrap_or(i128::MAX);
+                    let factor = pow10_u128(POWER.unsigned_abs()).and_then(|factor| i128::try_from(factor).ok());
+                    let secs = match (POWER >= 0, factor) {
+                        (true, Some(factor)) => value.checked_mul(factor),
+                        (true, None) => (value == 0).then_some(0),
+                        (false, Some(factor)) => Some(value.div_euclid(factor)),
+                        // the factor exceeds `|value|`
+                        (false, None) => Some(if value < 0 { -1 } else { 0 }),
+                    };
+                    let days = secs.map_or(if value < 0 { i128::MIN } else { i128::MAX }, |secs| secs.div_euclid(SECS_PER_DAY));
+                    i64::try_from(days).unwrap_or(if days < 0 { i64::MIN } else { i64::MAX })
+                }
+            }
+        )+
+    };
+}
+
+impl_unix_days!(u32, i32, u64, i64, u128, i128);
+
+macro_rules! impl_js_millis {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Returns the milliseconds since Unix epoch as `f64`, like the JavaScript `Date.getTime()` (may lose precision beyond 2^53)
+                #[inline]
+                pub fn to_js_millis(&self) -> f64 {
+                    self.as_millis_f64()
+                }
+
+                /// Converts the milliseconds since Unix epoch from the JavaScript `Date.getTime()`, or returns an error if `ms` is not a whole number or can't be represented exactly with this storage and power
+                #[inline]
+                pub fn from_js_millis(ms: f64) -> Result<Self, UnrepresentableJsMillisError> {
+                    // the range check also rejects `NaN` and infinities
+                    if !(-I128_BOUND_F64..I128_BOUND_F64).contains(&ms) || (ms as i128) as f64 != ms {
+                        return Err(UnrepresentableJsMillisError);
+                    }
+                    let ms = ms as i128;
+                    let exp = i64::from(MILLI) - i64::from(POWER);
+                    scale_u128(ms.unsigned_abs(), exp)
+                        .filter(|&magnitude| scale_u128(magnitude, -exp) == Some(ms.unsigned_abs()))
+                        .and_then(|magnitude| with_sign(ms < 0, magnitude))
+                        .and_then(|value| <$value>::try_from(value).ok())
+                        .map(Self::new)
+                        .ok_or(UnrepresentableJsMillisError)
+                }
+            }
+        )+
+    };
+}
+
+impl_js_millis!(u32, i32, u64, i64, u128, i128);
+
+macro_rules! impl_excel_serial {
+    ($($value:ty),+ $(,)?) => {
+        $(
+            impl<const POWER: i32> Timestamp<$value, POWER> {
+                /// Converts an Excel serial date (days since 1899-12-30 with a fractional day, in UTC) rounding to the nearest unit, or returns an error if it isn't finite or doesn't fit into the storage type (e.g. pre-1970 dates for unsigned storage)
+                ///
+                /// Excel treats 1900 as a leap year, so its serials below `61` (before 1900-03-01) are one day later than the dates returned here.
+                #[inline]
+                pub fn from_excel_serial(serial: f64) -> Result<Self, UnrepresentableExcelSerialError> {
+                    let units = scale_f64((serial - EXCEL_UNIX_EPOCH_SERIAL) * SECS_PER_DAY as f64, -i64::from(POWER));
+                    // the range check also rejects `NaN` and infinities
+                    if !(-I128_BOUND_F64..I128_BOUND_F64).contains(&units) {
+                        return Err(UnrepresentableExcelSerialError);
+                    }
+                    // `as` truncates toward zero, so shifting by a half rounds to the nearest unit
+                    let value = if units >= 0.0 { units + 0.5 } else { units - 0.5 } as i128;
+                    <$value>::try_from(value)
+                        .map(Self::new)
+                        .map_err(|_| UnrepresentableExcelSerialError)
+                }
+
+                /// Returns the Excel serial date (days since 1899-12-30 with a fractional day, in UTC) for the timestamp (may lose precision)
+                #[inline]
+                pub fn to_excel_serial(&self) -> f64 {
+                    self.as_secs_f64() / SECS_PER_DAY as f64 + EXCEL_UNIX_EPOCH_SERIAL
+                }
+            }
+        )+
+    };
+}
+
+impl_excel_serial!(u32, i32, u64, i64, u128, i128);
+
+/// The Excel serial date of Unix epoch (1970-01-01)
+const EXCEL_UNIX_EPOCH_SERIAL: f64 = 25_569.0;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct UnrepresentableExcelSerialError;
+
+impl fmt::Display for UnrepresentableExcelSerialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Excel serial date can't be represented as a timestamp with this storage and power")
+    }
+}
+
+impl core::error::Error for UnrepresentableExcelSerialError {}
+
+/// `2^127` (every whole `f64` strictly between `-2^127` and `2^127` fits into `i128`)
+const I128_BOUND_F64: f64 = 170_141_183_460_469_231_731_687_303_715_884_105_728.0;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct UnrepresentableJsMillisError;
+
+impl fmt::Display for UnrepresentableJsMillisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("JavaScript milliseconds can't be represented exactly as a timestamp with this storage and power")
+    }
+}
+
+impl core::error::Error for UnrepresentableJsMillisError {}
+
+/// Writes `value * 10^power` in fixed-point decimal notation (e.g. `1500` with `power = -3` is written as `1.500`)
+///
+/// Returns [`fmt::Error`] if the notation needs more than [`MAX_FIXED_POINT_ZEROS`] padding zeros (e.g. for `power = i32::MAX`).
+#[inline]
+pub fn format_fixed_point(value: impl itoa::Integer, power: i32, f: &mut impl fmt::Write) -> fmt::Result {
+    format_fixed_point_with_separator(value, power, '.', f)
+}
+
+/// Same as [`format_fixed_point`], but writes `separator` instead of the decimal point (e.g. `,` for some locales)
+#[inline]
+pub fn format_fixed_point_with_separator(value: impl itoa::Integer, power: i32, separator: char, f: &mut impl fmt::Write) -> fmt::Result {
+    write_fixed_point(value, power, separator, usize::MAX, f)
+}
+
+/// Same as [`format_fixed_point`], but writes at most `max_frac_digits` fractional digits, truncating the rest (the decimal point is omitted if `max_frac_digits` is zero)
+#[inline]
+pub fn format_fixed_point_with_max_fraction(value: impl itoa::Integer, power: i32, max_frac_digits: usize, f: &mut impl fmt::Write) -> fmt::Result {
+    write_fixed_point(value, power, '.', max_frac_digits, f)
+}
+
+/// Formats `value * 10^power` in scientific notation with one digit before the decimal point and without trailing zeros (e.g. `1.7e9`, or `0` for zero)
+#[inline]
+pub fn format_scientific_normalized(value: impl itoa::Integer, power: i32, f: &mut impl fmt::Write) -> fmt::Result {
+    let mut buffer = itoa::Buffer::new();
+    let raw = buffer.format(value);
+    let (sign, digits) = raw.strip_prefix('-').map_or(("", raw), |rest| ("-", rest));
+    if digits == "0" {
+        return f.write_str(digits);
+    }
+    let (first, rest) = digits.split_at(1);
+    let rest = rest.trim_end_matches('0');
+    let exp = i64::from(power) + (digits.len() - 1) as i64;
+    f.write_str(sign).and_then(|_| f.write_str(first))?;
+    if !rest.is_empty() {
+        f.write_char('.').and_then(|_| f.write_str(rest))?;
+    }
+    write!(f, "e{exp}")
+}
+
+#[inline]
+fn write_fixed_point(value: impl itoa::Integer, power: i32, separator: char, max_frac_digits: usize, f: &mut impl fmt::Write) -> fmt::Result {
+    let mut buffer = itoa::Buffer::new();
+    let raw = buffer.format(value);
+    let (sign, digits) = raw.strip_prefix('-').map_or(("", raw), |rest| ("-", rest));
+    let power = i64::from(power);
+
+    if power == 0 {
+        return f.write_str(sign).and_then(|_| f.write_str(digits));
+    }
+
+    if power > 0 {
+        // zero must not be padded (`0` instead of `000`)
+        if digits == "0" {
+            return f.write_str(digits);
+        }
+        // `i32` magnitudes fit into `usize` on 32-bit and 64-bit targets
+        let count = usize::try_from(power).map_err(|_| fmt::Error)?;
+        return f
+            .write_str(sign)
+            .and_then(|_| f.write_str(digits))
+            .and_then(|_| write_zeros(f, count));
+    }
+
+    let scale = usize::try_from(power.unsigned_abs()).map_err(|_| fmt::Error)?;
+    f.write_str(sign)?;
+
+    if digits.len() > scale {
+        let split = digits.len() - scale;
+        let (int_part, frac_part) = digits.split_at(split);
+        f.write_str(int_part)?;
+        if max_frac_digits == 0 {
+            return Ok(());
+        }
+        return f
+            .write_char(separator)
+            .and_then(|_| f.write_str(&frac_part[..frac_part.len().min(max_frac_digits)]));
+    }
+
+    f.write_str("0")?;
+    if max_frac_digits == 0 {
+        return Ok(());
+    }
+    let zeros = scale - digits.len();
+    f.write_char(separator)
+        .and_then(|_| write_zeros(f, zeros.min(max_frac_digits)))
+        .and_then(|_| f.write_str(&digits[..digits.len().min(max_frac_digits.saturating_sub(zeros))]))
+}
+
+/// Same as [`format_fixed_point`], but requires only [`fmt::Display`] (for custom integer types that don't implement `itoa::Integer`)
+///
+/// The `Display` impl must write an optional `-` followed by decimal digits. The value is formatted twice: once to count the digits and once to write them.
+#[inline]
+pub fn format_fixed_point_display(value: &impl fmt::Display, power: i32, f: &mut impl fmt::Write) -> fmt::Result {
+    use fmt::Write;
+    let mut counter = FixedPointWriter::new(None, '.', None, false);
+    write!(counter, "{value}")?;
+    let digits = counter.digits();
+
+    if power >= 0 {
+        if counter.is_zero() {
+            return f.write_str("0");
+        }
+        let count = usize::try_from(power).map_err(|_| fmt::Error)?;
+        return write!(f, "{value}").and_then(|_| write_zeros(f, count));
+    }
+
+    let scale = usize::try_from(power.unsigned_abs()).map_err(|_| fmt::Error)?;
+
+    if digits > scale {
+        let mut writer = FixedPointWriter::new(Some(f), '.', Some(digits - scale), false);
+        return write!(writer, "{value}");
+    }
+
+    let sign = if counter.is_negative() { "-" } else { "" };
+    f.write_str(sign)
+        .and_then(|_| f.write_str("0."))
+        .and_then(|_| write_zeros(f, scale - digits))?;
+    let mut writer = FixedPointWriter::new(Some(f), '.', None, true);
+    write!(writer, "{value}")
+}
+
+/// The maximum count of padding zeros in the fixed-point notation, so that a huge power fails fast instead of writing gigabytes of zeros
+pub const MAX_FIXED_POINT_ZEROS: usize = 1 << 16;
+
+/// Writes `count` zeros, or returns [`fmt::Error`] without writing anything if `count` exceeds [`MAX_FIXED_POINT_ZEROS`]
+#[inline]
+#[doc(hidden)]
+pub fn write_zeros(f: &mut impl fmt::Write, count: usize) -> fmt::Result {
+    if count > MAX_FIXED_POINT_ZEROS {
+        return Err(fmt::Error);
+    }
+
+    core::iter::repeat_n("0", count).try_for_each(|zero| f.write_str(zero))
+}
+
+/// Returns the latest timestamp, or `None` if `timestamps` is empty
+#[inline]
+pub fn latest<V: Ord, const POWER: i32>(timestamps: impl IntoIterator<Item = Timestamp<V, POWER>>) -> Option<Timestamp<V, POWER>> {
+    timestamps.into_iter().max()
+}
+
+/// Returns the earliest timestamp, or `None` if `timestamps` is empty
+#[inline]
+pub fn earliest<V: Ord, const POWER: i32>(timestamps: impl IntoIterator<Item = Timestamp<V, POWER>>) -> Option<Timestamp<V, POWER>> {
+    timestamps.into_iter().min()
+}
+
+/// Lazily scales every timestamp into `POWER_OUT` units (see [`Timestamp::try_scale`]), e.g. to `.collect::<Result<Vec<_>, _>>()`
+#[inline]
+pub fn rescale_iter<V: Into<u128> + TryFrom<u128>, const POWER_IN: i32, const POWER_OUT: i32>(timestamps: impl IntoIterator<Item = Timestamp<V, POWER_IN>>) -> impl Iterator<Item = Result<Timestamp<V, POWER_OUT>, TimestampTryScaleError>> {
+    timestamps.into_iter().map(Timestamp::try_scale)
+}
+
+/// Returns the absolute time between two timestamps with different powers, or `None` on overflow or if either timestamp has non-zero digits below a nanosecond
+#[inline]
+pub fn duration_between<const PA: i32, const PB: i32>(a: Timestamp<u128, PA>, b: Timestamp<u128, PB>) -> Option<Duration> {
+    let a_ns = exact_timestamp_value_to_nanoseconds(a.into_value(), PA)?;
+    let b_ns = exact_timestamp_value_to_nanoseconds(b.into_value(), PB)?;
+    checked_nanoseconds_to_duration(a_ns.abs_diff(b_ns))
+}
+
+/// Returns `a - b` in nanoseconds (positive if `a` is later) for timestamps with different powers, or `None` on overflow or if either timestamp has non-zero digits below a nanosecond
+#[inline]
+pub fn signed_units_between<const PA: i32, const PB: i32>(a: Timestamp<u128, PA>, b: Timestamp<u128, PB>) -> Option<i128> {
+    let a_ns = exact_timestamp_value_to_nanoseconds(a.into_value(), PA)?;
+    let b_ns = exact_timestamp_value_to_nanoseconds(b.into_value(), PB)?;
+    with_sign(a_ns < b_ns, a_ns.abs_diff(b_ns))
+}
+
+/// Returns the ASCII name of the unit that matches `power` (`"s"`, `"ms"`, `"us"` or `"ns"`), or an empty string for non-standard powers
+#[inline]
+pub const fn unit_name(power: i32) -> &'static str {
+    match power {
+        UNO => "s",
+        MILLI => "ms",
+        MICRO => "us",
+        NANO => "ns",
+        _ => "",
+    }
+}
+
+/// Returns the count of `10^POWER` seconds in `duration` (digits below the unit are truncated), or `None` on overflow
+#[inline]
+pub const fn duration_to_units<const POWER: i32>(duration: Duration) -> Option<u128> {
+    nanoseconds_to_timestamp_value(duration.as_nanos(), POWER)
+}
+
+/// Converts `value * 10^power` seconds into nanoseconds (sub-nanosecond digits are truncated)
+#[inline]
+pub const fn timestamp_value_to_nanoseconds(value: u128, power: i32) -> Option<u128> {
+    scale_u128(value, power as i64 - NANO as i64)
+}
+
+/// Converts nanoseconds into the count of `10^power` seconds (digits below the unit are truncated)
+#[inline]
+pub const fn nanoseconds_to_timestamp_value(total_ns: u128, power: i32) -> Option<u128> {
+    scale_u128(total_ns, NANO as i64 - power as i64)
+}
+
+/// Converts `value * 10^power` seconds into nanoseconds, or returns `None` if the conversion is lossy
+#[inline]
+pub fn exact_timestamp_value_to_nanoseconds(value: u128, power: i32) -> Option<u128> {
+    timestamp_value_to_nanoseconds(value, power).filter(|&total_ns| nanoseconds_to_timestamp_value(total_ns, power) == Some(value))
+}
+
+/// Converts nanoseconds into the count of `10^power` seconds, or returns `None` if the conversion is lossy
+#[inline]
+pub fn exact_nanoseconds_to_timestamp_value(total_ns: u128, power: i32) -> Option<u128> {
+    nanoseconds_to_timestamp_value(total_ns, power).filter(|&value| timestamp_value_to_nanoseconds(value, power) == Some(total_ns))
+}
+
+/// Signed variant of [`exact_timestamp_value_to_nanoseconds`]
+#[inline]
+pub fn exact_signed_timestamp_value_to_nanoseconds(value: i128, power: i32) -> Option<i128> {
+    exact_timestamp_value_to_nanoseconds(value.unsigned_abs(), power).and_then(|magnitude| with_sign(value < 0, magnitude))
+}
+
+/// Signed variant of [`nanoseconds_to_timestamp_value`] that floors the digits below the unit (toward the past instead of toward the epoch)
+#[inline]
+pub fn floor_signed_nanoseconds_to_timestamp_value(total_ns: i128, power: i32) -> Option<i128> {
+    let negative = total_ns < 0;
+    let magnitude = total_ns.unsigned_abs();
+    let truncated = nanoseconds_to_timestamp_value(magnitude, power)?;
+    // rounding the magnitude up floors a negative value; the increment can't overflow because an inexact division divides by at least 10
+    let inexact = exact_nanoseconds_to_timestamp_value(magnitude, power).is_none();
+    with_sign(negative, if negative && inexact { truncated + 1 } else { truncated })
+}
+
+/// Signed variant of [`exact_nanoseconds_to_timestamp_value`]
+#[inline]
+pub fn exact_signed_nanoseconds_to_timestamp_value(total_ns: i128, power: i32) -> Option<i128> {
+    exact_nanoseconds_to_timestamp_value(total_ns.unsigned_abs(), power).and_then(|magnitude| with_sign(total_ns < 0, magnitude))
+}
+
+#[inline]
+fn with_sign(negative: bool, magnitude: u128) -> Option<i128> {
+    if negative { 0i128.checked_sub_unsigned(magnitude) } else { i128::try_from(magnitude).ok() }
+}
+
+/// Returns `value * 10^exp` (digits below the unit are truncated), or `None` on overflow
+#[inline]
+pub const fn scale_u128(value: u128, exp: i64) -> Option<u128> {
+    if value == 0 {
+        return Some(0);
+    }
+
+    let exp_abs = exp.unsigned_abs();
+    let factor = if exp_abs > u32::MAX as u64 { None } else { pow10_u128(exp_abs as u32) };
+
+    match (exp >= 0, factor) {
+        (true, Some(factor)) => value.checked_mul(factor),
+        (true, None) => None,
+        (false, Some(factor)) => Some(value / factor),
+        (false, None) => Some(0),
+    }
+}
+
+/// Same as [`scale_u128`], but rounds the digits below the unit according to `mode`
+#[inline]
+pub const fn scale_u128_with_rounding(value: u128, exp: i64, mode: RoundingMode) -> Option<u128> {
+    use RoundingMode::*;
+    if exp >= 0 {
+        return scale_u128(value, exp);
+    }
+
+    let exp_abs = exp.unsigned_abs();
+    let factor = if exp_abs > u32::MAX as u64 { None } else { pow10_u128(exp_abs as u32) };
+    // `value` is below half of any factor that overflows `u128`, so it rounds to zero in every mode
+    let Some(factor) = factor else {
+        return Some(0);
+    };
+
+    let (quotient, remainder) = (value / factor, value % factor);
+    let half = factor / 2;
+    let round_up = match mode {
+        Truncate => false,
+        Nearest => remainder >= half,
+        NearestEven => remainder > half || (remainder == half && quotient % 2 == 1),
+    };
+    // `quotient` is at most `u128::MAX / 10`, so the increment can't overflow
+    Some(if round_up { quotient + 1 } else { quotient })
+}
+
+/// Converts nanoseconds into a [`Duration`], or returns `None` if the whole seconds exceed `u64::MAX`
+///
+/// Never panics: the nanosecond part is always below one second, so `Duration::new` doesn't carry into the seconds.
+#[inline]
+pub const fn checked_nanoseconds_to_duration(total_ns: u128) -> Option<Duration> {
+    let secs = total_ns / NANOS_PER_SECOND;
+    let nanos = (total_ns % NANOS_PER_SECOND) as u32;
+    if secs > u64::MAX as u128 { None } else { Some(Duration::new(secs as u64, nanos)) }
+}
+
+/// Same as [`checked_nanoseconds_to_duration`], but saturates at [`Duration::MAX`] (including for `u128::MAX`)
+#[inline]
+pub const fn nanoseconds_to_duration(total_ns: u128) -> Duration {
+    match checked_nanoseconds_to_duration(total_ns) {
+        Some(duration) => duration,
+        None => Duration::MAX,
+    }
+}
+
+/// Returns `a * b / divisor` (truncated) with a 256-bit intermediate product, or `None` if `divisor` is zero or the result overflows `u128`
+#[inline]
+const fn mul_div_u128(a: u128, b: u128, divisor: u128) -> Option<u128> {
+    if divisor == 0 {
+        return None;
+    }
+    let (high, low) = widening_mul_u128(a, b);
+    if high == 0 {
+        return Some(low / divisor);
+    }
+    // the quotient fits into `u128` only if the high half is below the divisor
+    if high >= divisor {
+        return None;
+    }
+    // restoring long division of the 256-bit product, one bit of `low` at a time
+    let (mut remainder, mut quotient, mut bit) = (high, 0u128, 128);
+    while bit > 0 {
+        bit -= 1;
+        let carry = remainder >> 127 == 1;
+        remainder = remainder << 1 | (low >> bit & 1);
+        quotient <<= 1;
+        if carry || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+    Some(quotient)
+}
+
+/// Returns the `(high, low)` halves of the 256-bit product `a * b`
+#[inline]
+const fn widening_mul_u128(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_high, a_low, b_high, b_low) = (a >> 64, a & MASK, b >> 64, b & MASK);
+    let (low_low, low_high, high_low) = (a_low * b_low, a_low * b_high, a_high * b_low);
+    // the sum of three 64-bit halves fits into `u128`
+    let middle = (low_low >> 64) + (low_high & MASK) + (high_low & MASK);
+    let low = middle << 64 | (low_low & MASK);
+    let high = a_high * b_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+    (high, low)
+}
+
+/// Returns `a * b % modulus` without overflow (`modulus` must be non-zero and below `2^127`)
+#[inline]
+const fn mul_rem_u128(a: u128, b: u128, modulus: u128) -> u128 {
+    let (mut a, mut b, mut remainder) = (a % modulus, b, 0);
+    while b > 0 {
+        if b & 1 == 1 {
+            remainder = (remainder + a) % modulus;
+        }
+        a = (a << 1) % modulus;
+        b >>= 1;
+    }
+    remainder
+}
+
+/// Returns `10^exp % modulus` without overflow (`modulus` must be non-zero and below `2^127`)
+#[inline]
+const fn pow10_rem_u128(exp: u32, modulus: u128) -> u128 {
+    let (mut base, mut exp, mut remainder) = (10 % modulus, exp, 1 % modulus);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            remainder = mul_rem_u128(remainder, base, modulus);
+        }
+        base = mul_rem_u128(base, base, modulus);
+        exp >>= 1;
+    }
+    remainder
+}
+
+macro_rules! pow10_table {
+    ($value:ty, $max:expr) => {{
+        let mut table = [1 as $value; $max as usize + 1];
+        let mut exp = 1;
+        while exp < table.len() {
+            table[exp] = table[exp - 1] * 10;
+            exp += 1;
+        }
+        table
+    }};
+}
+
+const POW10_U64: [u64; MAX_POW10_U64 as usize + 1] = pow10_table!(u64, MAX_POW10_U64);
+const POW10_U128: [u128; MAX_POW10_U128 as usize + 1] = pow10_table!(u128, MAX_POW10_U128);
+
+#[inline]
+pub const fn pow10_u64(exp: u32) -> Option<u64> {
+    if exp as u64 > MAX_POW10_U64 {
+        return None;
+    }
+
+    Some(POW10_U64[exp as usize])
+}
+
+#[inline]
+pub const fn pow10_u128(exp: u32) -> Option<u128> {
+    if exp as u64 > MAX_POW10_U128 {
+        return None;
+    }
+
+    Some(POW10_U128[exp as usize])
+}
+
+/// Returns `value * 10^exp`
+#[inline]
+pub fn scale_f64(value: f64, exp: i64) -> f64 {
+    if value == 0.0 {
+        // avoids `0 * inf = NaN` for huge exponents
+        return value;
+    }
+
+    let factor = pow10_f64(exp.unsigned_abs());
+    if exp >= 0 { value * factor } else { value / factor }
+}
+
+/// `10^(2^bit)` for every bit of an exponent below [`MAX_POW10_F64`]
+const POW10_SQUARES_F64: [f64; 9] = [1e1, 1e2, 1e4, 1e8, 1e16, 1e32, 1e64, 1e128, 1e256];
+
+/// The smallest exponent for which `10^exp` overflows `f64` regardless of the rounding of the squares
+const MAX_POW10_F64: u64 = 1 << POW10_SQUARES_F64.len();
+
+/// Returns `10^exp` in `O(log exp)` multiplications (exact up to `10^22`), or infinity if it overflows `f64`
+#[inline]
+pub fn pow10_f64(exp: u64) -> f64 {
+    if exp >= MAX_POW10_F64 {
+        return f64::INFINITY;
+    }
+
+    POW10_SQUARES_F64
+        .iter()
+        .enumerate()
+        .filter(|(bit, _)| exp >> bit & 1 == 1)
+        .map(|(_, square)| square)
+        .product()
+}
+
+/// Gathers the formatters that return a `String` (including the feature-gated ones), so that the rest of the crate stays usable without `alloc`
+#[cfg(feature = "alloc")]
+mod fmt_alloc {
+    use super::*;
+    use alloc::format;
+    use alloc::string::String;
+    #[cfg(feature = "chrono")]
+    use alloc::string::ToString;
+    #[cfg(feature = "chrono")]
+    use chrono::{DateTime, Utc};
+    #[cfg(feature = "chrono-tz")]
+    use core::fmt::Write;
+
+    impl<V: fmt::LowerHex, const POWER: i32> Timestamp<V, POWER> {
+        /// Formats the raw value in lowercase hex without a prefix (e.g. `18bcfe56800` for `1700000000000`)
+        #[inline]
+        pub fn to_hex(&self) -> String {
+            format!("{:x}", self.value)
+        }
+    }
+
+    impl<V: Copy + Into<u128>, const POWER: i32> Timestamp<V, POWER> {
+        /// Describes `self` relative to `now` in coarse units, truncating toward zero (e.g. `3 minutes ago`, `in 2 hours`, `just now` for gaps below one second)
+        pub fn humanize_relative(&self, now: Self) -> String {
+            const BUCKETS: [(u128, &str); 4] = [
+                (86_400, "day"),
+                (3_600, "hour"),
+                (60, "minute"),
+                (1, "second"),
+            ];
+            let (value, now) = (self.value.into(), now.value.into());
+            let secs = timestamp_value_to_nanoseconds(value.abs_diff(now), POWER).map_or(u128::MAX, |diff_ns| diff_ns / NANOS_PER_SECOND);
+            let Some((count, unit)) = BUCKETS
+                .iter()
+                .find(|(unit_secs, _)| secs >= *unit_secs)
+                .map(|(unit_secs, unit)| (secs / unit_secs, unit))
+            else {
+                return "just now".into();
+            };
+            let plural = if count == 1 { "" } else { "s" };
+            if value < now {
+                format!("{count} {unit}{plural} ago")
+            } else {
+                format!("in {count} {unit}{plural}")
+            }
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    impl<V, const POWER: i32> Timestamp<V, POWER>
+    where
+        Self: Copy + TryInto<DateTime<Utc>, Error = UnrepresentableChronoDateTimeError>,
+    {
+        /// Formats the timestamp as an RFC 3339 string in UTC with the fractional precision that matches `POWER` (e.g. `2023-11-14T22:13:20.123Z` for milliseconds)
+        #[inline]
+        pub fn to_rfc3339(&self) -> Result<String, UnrepresentableChronoDateTimeError> {
+            use chrono::SecondsFormat::*;
+            let format = match POWER {
+                0.. => Secs,
+                MILLI..0 => Millis,
+                MICRO..MILLI => Micros,
+                _ => Nanos,
+            };
+            (*self)
+                .try_into()
+                .map(|dt| dt.to_rfc3339_opts(format, true))
+        }
+
+        /// Formats the timestamp in the ISO 8601 basic format in UTC at second precision (e.g. `20231114T221320Z`, useful for file names)
+        #[inline]
+        pub fn to_iso8601_basic(&self) -> Result<String, UnrepresentableChronoDateTimeError> {
+            (*self)
+                .try_into()
+                .map(|dt| dt.format("%Y%m%dT%H%M%SZ").to_string())
+        }
+
+        /// Formats the timestamp as an RFC 2822 date in UTC, truncating the fractional seconds, or returns an error if the year is outside `0..=9999`
+        #[inline]
+        pub fn to_rfc2822(&self) -> Result<String, UnrepresentableChronoDateTimeError> {
+            use chrono::Datelike;
+            let dt: DateTime<Utc> = (*self).try_into()?;
+            if (0..=9999).contains(&dt.year()) {
+                Ok(dt.to_rfc2822())
+            } else {
+                Err(UnrepresentableChronoDateTimeError)
+            }
+        }
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    impl<V, const POWER: i32> Timestamp<V, POWER>
+    where
+        Self: Copy + TryInto<DateTime<Utc>, Error = UnrepresentableChronoDateTimeError>,
+    {
+        /// Formats the timestamp in the `tz` timezone using a strftime-like `fmt` pattern (see [`chrono::format::strftime`])
+        pub fn format_in_tz(&self, tz: chrono_tz::Tz, fmt: &str) -> Result<String, FormatInTzError> {
+            use FormatInTzError::*;
+            let dt = (*self).try_into().map_err(|source| Unrepresentable {
+                source,
+            })?;
+            let mut output = String::new();
+            write!(output, "{}", dt.with_timezone(&tz).format(fmt)).map_err(|_| InvalidFormat)?;
+            Ok(output)
+        }
+    }
+
+    /// The first second of year 10000, which `httpdate` can't format
+    #[cfg(feature = "httpdate")]
+    const MAX_HTTP_DATE_SECS: u64 = 253_402_300_800;
+
+    #[cfg(feature = "httpdate")]
+    macro_rules! impl_to_http_date {
+        ($($value:ty),+ $(,)?) => {
+            $(
+                impl<const POWER: i32> Timestamp<$value, POWER> {
+                    /// Formats the timestamp as an HTTP date (RFC 7231), truncating the fractional seconds
+                    pub fn to_http_date(&self) -> Result<String, UnrepresentableHttpDateError> {
+                        Some(self.unix_secs())
+                            .filter(|&secs| secs < MAX_HTTP_DATE_SECS)
+                            .and_then(|secs| std::time::UNIX_EPOCH.checked_add(Duration::from_secs(secs)))
+                            .map(httpdate::fmt_http_date)
+                            .ok_or(UnrepresentableHttpDateError)
+                    }
+                }
+            )+
+        };
+    }
+
+    #[cfg(feature = "httpdate")]
+    impl_to_http_date!(u32, u64, u128);
+}
+
+#[cfg(feature = "std")]
+mod interop_std {
+    use super::*;
+
+    macro_rules! impl_from_system_time {
+        ($($target:ty),+ $(,)?) => {
+            $(
+                impl $target {
+                    /// Returns the underlying error if `system_time` is before Unix epoch
+                    #[inline]
+                    pub fn from_system_time(system_time: std::time::SystemTime) -> Result<Self, std::time::SystemTimeError> {
+                        system_time.duration_since(std::time::UNIX_EPOCH).map(Self::from)
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_from_system_time!(Timestamp<u64, UNO>, Timestamp<u128, MILLI>, Timestamp<u128, MICRO>, Timestamp<u128, NANO>);
+
+    macro_rules! impl_try_from_system_time {
+        ($($target:ty),+ $(,)?) => {
+            $(
+                impl TryFrom<std::time::SystemTime> for $target {
+                    type Error = std::time::SystemTimeError;
+
+                    #[inline]
+                    fn try_from(system_time: std::time::SystemTime) -> Result<Self, Self::Error> {
+                        Self::from_system_time(system_time)
+                    }
+                }
+            )+
+        };
+    }
+
+    // `Timestamp<u64, UNO>` is covered by the generic impl below
+    impl_try_from_system_time!(Timestamp<u128, MILLI>, Timestamp<u128, MICRO>, Timestamp<u128, NANO>);
+
+    /// Converts the time since Unix epoch into `POWER` units, truncating the digits below the unit (use [`Timestamp::from_system_time_exact`] to reject them)
+    impl<const POWER: i32> TryFrom<std::time::SystemTime> for Timestamp<u64, POWER> {
+        type Error = FromSystemTimeError;
+
+        #[inline]
+        fn try_from(system_time: std::time::SystemTime) -> Result<Self, Self::Error> {
+            use FromSystemTimeError::*;
+            let duration = system_time
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_err(|source| BeforeEpoch {
+                    source,
+                })?;
+            nanoseconds_to_timestamp_value(duration.as_nanos(), POWER)
+                .and_then(|value| u64::try_from(value).ok())
                 .map(Self::new)
-                .ok_or(UnrepresentableChronoDateTimeError)
+                .ok_or(Overflow)
+        }
+    }
+
+    macro_rules! impl_from_system_time_exact {
+        ($($value:ty),+ $(,)?) => {
+            $(
+                impl<const POWER: i32> Timestamp<$value, POWER> {
+                    /// Same as the truncating `TryFrom<SystemTime>`, but also returns [`FromSystemTimeError::Inexact`] if `system_time` has non-zero digits below the unit
+                    #[inline]
+                    pub fn from_system_time_exact(system_time: std::time::SystemTime) -> Result<Self, FromSystemTimeError> {
+                        use FromSystemTimeError::*;
+                        let total_ns = system_time
+                            .duration_since(std::time::UNIX_EPOCH)
+                            .map_err(|source| BeforeEpoch {
+                                source,
+                            })?
+                            .as_nanos();
+                        nanoseconds_to_timestamp_value(total_ns, POWER).ok_or(Overflow)?;
+                        exact_nanoseconds_to_timestamp_value(total_ns, POWER)
+                            .ok_or(Inexact)
+                            .and_then(|value| <$value>::try_from(value).map_err(|_| Overflow))
+                            .map(Self::new)
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_from_system_time_exact!(u32, u64, u128);
+
+    #[derive(Clone, Debug)]
+    pub enum FromSystemTimeError {
+        BeforeEpoch {
+            source: std::time::SystemTimeError,
+        },
+        Overflow,
+        /// Only returned by [`Timestamp::from_system_time_exact`]
+        Inexact,
+    }
+
+    impl fmt::Display for FromSystemTimeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            use FromSystemTimeError::*;
+            match self {
+                BeforeEpoch {
+                    source,
+                } => source.fmt(f),
+                Overflow => f.write_str("system time doesn't fit into the storage type with this power"),
+                Inexact => f.write_str("system time has non-zero digits below the unit of this power"),
+            }
+        }
+    }
+
+    impl core::error::Error for FromSystemTimeError {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            use FromSystemTimeError::*;
+            match self {
+                BeforeEpoch {
+                    source,
+                } => Some(source),
+                Overflow | Inexact => None,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub use interop_std::*;
+
+#[cfg(feature = "time")]
+mod interop_time {
+    use super::*;
+    use time::error::ComponentRange;
+    use time::{OffsetDateTime, UtcOffset};
+
+    impl From<OffsetDateTime> for Timestamp<i128, NANO> {
+        #[inline]
+        fn from(dt: OffsetDateTime) -> Self {
+            Timestamp::new(dt.unix_timestamp_nanos())
         }
     }
 
-    impl TryFrom<Timestamp<i128, NANO>> for DateTime<Utc> {
-        type Error = UnrepresentableChronoDateTimeError;
+    impl TryFrom<Timestamp<i128, NANO>> for OffsetDateTime {
+        type Error = ComponentRange;
 
         #[inline]
         fn try_from(timestamp: Timestamp<i128, NANO>) -> Result<Self, Self::Error> {
-            let nanos: i64 = timestamp
-                .value
-                .try_into()
-                .map_err(|_| UnrepresentableChronoDateTimeError)?;
-            Ok(Self::from_timestamp_nanos(nanos))
+            OffsetDateTime::from_unix_timestamp_nanos(timestamp.value)
+        }
+    }
+
+    macro_rules! impl_offset_date_time_methods {
+        ($($value:ty => $earliest_ns:expr),+ $(,)?) => {
+            $(
+                impl<const POWER: i32> Timestamp<$value, POWER> {
+                    /// Converts the datetime into a timestamp, flooring the digits below the unit (toward the past)
+                    ///
+                    /// Unsigned storage clamps the pre-epoch datetimes to zero, so the epoch and the datetimes before it all become `0` (use signed storage to keep them apart). Returns an error only if the value overflows the storage.
+                    #[inline]
+                    pub fn from_offset_datetime(dt: OffsetDateTime) -> Result<Self, UnrepresentableOffsetDateTimeError> {
+                        floor_signed_nanoseconds_to_timestamp_value(dt.unix_timestamp_nanos().max($earliest_ns), POWER)
+                            .and_then(|value| <$value>::try_from(value).ok())
+                            .map(Self::new)
+                            .ok_or(UnrepresentableOffsetDateTimeError)
+                    }
+
+                    /// Converts the timestamp into an `OffsetDateTime` in the given UTC `offset` (instead of UTC), or returns an error if it has digits below a nanosecond or is out of the `OffsetDateTime` range
+                    #[inline]
+                    pub fn to_offset_datetime(self, offset: UtcOffset) -> Result<OffsetDateTime, UnrepresentableOffsetDateTimeError> {
+                        i128::try_from(self.value)
+                            .ok()
+                            .and_then(|value| exact_signed_timestamp_value_to_nanoseconds(value, POWER))
+                            .and_then(|total_ns| OffsetDateTime::from_unix_timestamp_nanos(total_ns).ok())
+                            .and_then(|dt| dt.checked_to_offset(offset))
+                            .ok_or(UnrepresentableOffsetDateTimeError)
+                    }
+                }
+            )+
+        };
+    }
+
+    // unsigned storage starts at the epoch, signed storage accepts every datetime
+    impl_offset_date_time_methods!(u32 => 0, u64 => 0, u128 => 0, i32 => i128::MIN, i64 => i128::MIN, i128 => i128::MIN);
+
+    macro_rules! impl_offset_date_time_conversions {
+        ($($value:ty),+ $(,)?) => {
+            $(
+                /// See [`Timestamp::from_offset_datetime`]
+                impl<const POWER: i32> TryFrom<OffsetDateTime> for Timestamp<$value, POWER> {
+                    type Error = UnrepresentableOffsetDateTimeError;
+
+                    #[inline]
+                    fn try_from(dt: OffsetDateTime) -> Result<Self, Self::Error> {
+                        Self::from_offset_datetime(dt)
+                    }
+                }
+
+                impl<const POWER: i32> TryFrom<Timestamp<$value, POWER>> for OffsetDateTime {
+                    type Error = UnrepresentableOffsetDateTimeError;
+
+                    #[inline]
+                    fn try_from(timestamp: Timestamp<$value, POWER>) -> Result<Self, Self::Error> {
+                        timestamp.to_offset_datetime(UtcOffset::UTC)
+                    }
+                }
+            )+
+        };
+    }
+
+    // `i128` is excluded because the traits would overlap with the conversions of `Timestamp<i128, NANO>` (use the methods above for the other powers)
+    impl_offset_date_time_conversions!(u32, u64, u128, i32, i64);
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct UnrepresentableOffsetDateTimeError;
+
+    impl fmt::Display for UnrepresentableOffsetDateTimeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("offset datetime is out of range for a timestamp with this storage and power, or the timestamp can't be represented exactly as an offset datetime")
+        }
+    }
+
+    impl core::error::Error for UnrepresentableOffsetDateTimeError {}
+
+    macro_rules! impl_time_duration_conversions {
+        ($($value:ty),+ $(,)?) => {
+            $(
+                /// Converts the time since Unix epoch into a (non-negative) `time::Duration`
+                impl<const POWER: i32> TryFrom<Timestamp<$value, POWER>> for time::Duration {
+                    type Error = UnrepresentableTimeDurationError;
+
+                    #[inline]
+                    fn try_from(timestamp: Timestamp<$value, POWER>) -> Result<Self, Self::Error> {
+                        let total_ns = exact_timestamp_value_to_nanoseconds(u128::from(timestamp.value), POWER).ok_or(UnrepresentableTimeDurationError)?;
+                        let secs = i64::try_from(total_ns / NANOS_PER_SECOND).map_err(|_| UnrepresentableTimeDurationError)?;
+                        let nanos = (total_ns % NANOS_PER_SECOND) as i32;
+                        Ok(Self::new(secs, nanos))
+                    }
+                }
+
+                /// Interprets the `time::Duration` as the time since Unix epoch (negative durations are rejected)
+                impl<const POWER: i32> TryFrom<time::Duration> for Timestamp<$value, POWER> {
+                    type Error = UnrepresentableTimeDurationError;
+
+                    #[inline]
+                    fn try_from(duration: time::Duration) -> Result<Self, Self::Error> {
+                        u128::try_from(duration.whole_nanoseconds())
+                            .ok()
+                            .and_then(|total_ns| exact_nanoseconds_to_timestamp_value(total_ns, POWER))
+                            .and_then(|value| <$value>::try_from(value).ok())
+                            .map(Self::new)
+                            .ok_or(UnrepresentableTimeDurationError)
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_time_duration_conversions!(u32, u64, u128);
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct UnrepresentableTimeDurationError;
+
+    impl fmt::Display for UnrepresentableTimeDurationError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("time duration can't be represented exactly as a timestamp with this storage and power")
+        }
+    }
+
+    impl core::error::Error for UnrepresentableTimeDurationError {}
+}
+
+#[cfg(feature = "time")]
+pub use interop_time::*;
+
+#[cfg(feature = "chrono")]
+mod interop_chrono {
+    use super::*;
+    use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+    macro_rules! impl_chrono_date_time_conversions {
+        ($($value:ty),+ $(,)?) => {
+            $(
+                impl<const POWER: i32> TryFrom<Timestamp<$value, POWER>> for DateTime<Utc> {
+                    type Error = UnrepresentableChronoDateTimeError;
+
+                    #[inline]
+                    fn try_from(timestamp: Timestamp<$value, POWER>) -> Result<Self, Self::Error> {
+                        let total_ns = i128::try_from(timestamp.value)
+                            .ok()
+                            .and_then(|value| exact_signed_timestamp_value_to_nanoseconds(value, POWER))
+                            .ok_or(UnrepresentableChronoDateTimeError)?;
+                        let secs = i64::try_from(total_ns.div_euclid(NANOS_PER_SECOND as i128)).map_err(|_| UnrepresentableChronoDateTimeError)?;
+                        let nanos = total_ns.rem_euclid(NANOS_PER_SECOND as i128) as u32;
+                        Self::from_timestamp(secs, nanos).ok_or(UnrepresentableChronoDateTimeError)
+                    }
+                }
+
+                impl<Tz: TimeZone, const POWER: i32> TryFrom<DateTime<Tz>> for Timestamp<$value, POWER> {
+                    type Error = UnrepresentableChronoDateTimeError;
+
+                    #[inline]
+                    fn try_from(dt: DateTime<Tz>) -> Result<Self, Self::Error> {
+                        let total_ns = i128::from(dt.timestamp()) * NANOS_PER_SECOND as i128 + i128::from(dt.timestamp_subsec_nanos());
+                        exact_signed_nanoseconds_to_timestamp_value(total_ns, POWER)
+                            .and_then(|value| <$value>::try_from(value).ok())
+                            .map(Self::new)
+                            .ok_or(UnrepresentableChronoDateTimeError)
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_chrono_date_time_conversions!(u32, u64, u128, i32, i64, i128);
+
+    macro_rules! impl_clamped_for_chrono {
+        ($($value:ty),+ $(,)?) => {
+            $(
+                impl<const POWER: i32> Timestamp<$value, POWER> {
+                    /// Clamps the timestamp into the `i64` nanosecond range and truncates the sub-nanosecond digits, so that the conversion into `DateTime<Utc>` always succeeds
+                    #[inline]
+                    pub fn clamped_for_chrono(self) -> Self {
+                        // only `u128` values above `i128::MAX` fail here, and they are clamped anyway
+                        let value = i128::try_from(self.value).unwrap_or(i128::MAX);
+                        let limit = if value < 0 { u128::from(i64::MIN.unsigned_abs()) } else { i64::MAX as u128 };
+                        let total_ns = timestamp_value_to_nanoseconds(value.unsigned_abs(), POWER).unwrap_or(u128::MAX).min(limit);
+                        nanoseconds_to_timestamp_value(total_ns, POWER)
+                            .and_then(|magnitude| with_sign(value < 0, magnitude))
+                            .and_then(|value| <$value>::try_from(value).ok())
+                            .map_or(self, Self::new)
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_clamped_for_chrono!(u32, u64, u128, i32, i64, i128);
+
+    impl<V, const POWER: i32> Timestamp<V, POWER>
+    where
+        Self: TryFrom<DateTime<Utc>, Error = UnrepresentableChronoDateTimeError>,
+    {
+        /// Returns the start of the `year` in UTC
+        #[inline]
+        pub fn start_of_year(year: i32) -> Result<Self, CalendarBoundaryError> {
+            Self::start_of_day(year, 1, 1)
+        }
+
+        /// Returns the start of the day in UTC
+        #[inline]
+        pub fn start_of_day(year: i32, month: u32, day: u32) -> Result<Self, CalendarBoundaryError> {
+            use CalendarBoundaryError::*;
+            let dt = NaiveDate::from_ymd_opt(year, month, day)
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .ok_or(InvalidDate)?
+                .and_utc();
+            Self::try_from(dt).map_err(|source| Unrepresentable {
+                source,
+            })
+        }
+
+        /// Parses an RFC 2822 date (e.g. `Tue, 14 Nov 2023 22:13:20 +0000`), converting it into UTC
+        #[inline]
+        pub fn from_rfc2822(s: &str) -> Result<Self, FromRfc2822Error> {
+            use FromRfc2822Error::*;
+            let dt = DateTime::parse_from_rfc2822(s).map_err(|source| InvalidRfc2822 {
+                source,
+            })?;
+            Self::try_from(dt.with_timezone(&Utc)).map_err(|source| Unrepresentable {
+                source,
+            })
+        }
+    }
+
+    impl<V, const POWER: i32> Timestamp<V, POWER>
+    where
+        Self: Copy + TryInto<DateTime<Utc>, Error = UnrepresentableChronoDateTimeError>,
+    {
+        /// Returns the time until the next UTC midnight strictly after the timestamp (a full day if the timestamp is exactly at midnight)
+        #[inline]
+        pub fn until_next_midnight_utc(&self) -> Result<Duration, UnrepresentableChronoDateTimeError> {
+            let dt: DateTime<Utc> = (*self).try_into()?;
+            dt.date_naive()
+                .succ_opt()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .and_then(|midnight| (midnight.and_utc() - dt).to_std().ok())
+                .ok_or(UnrepresentableChronoDateTimeError)
+        }
+    }
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum CalendarBoundaryError {
+        InvalidDate,
+        Unrepresentable { source: UnrepresentableChronoDateTimeError },
+    }
+
+    impl fmt::Display for CalendarBoundaryError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            use CalendarBoundaryError::*;
+            match self {
+                InvalidDate => f.write_str("date is invalid or out of range"),
+                Unrepresentable {
+                    source,
+                } => source.fmt(f),
+            }
+        }
+    }
+
+    impl core::error::Error for CalendarBoundaryError {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            use CalendarBoundaryError::*;
+            match self {
+                InvalidDate => None,
+                Unrepresentable {
+                    source,
+                } => Some(source),
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub enum FromRfc2822Error {
+        InvalidRfc2822 { source: chrono::ParseError },
+        Unrepresentable { source: UnrepresentableChronoDateTimeError },
+    }
+
+    impl fmt::Display for FromRfc2822Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            use FromRfc2822Error::*;
+            match self {
+                InvalidRfc2822 {
+                    source,
+                } => source.fmt(f),
+                Unrepresentable {
+                    source,
+                } => source.fmt(f),
+            }
+        }
+    }
+
+    impl core::error::Error for FromRfc2822Error {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            use FromRfc2822Error::*;
+            match self {
+                InvalidRfc2822 {
+                    source,
+                } => Some(source),
+                Unrepresentable {
+                    source,
+                } => Some(source),
+            }
         }
     }
 
@@ -272,3 +2545,163 @@ mod interop_chrono {
 
 #[cfg(feature = "chrono")]
 pub use interop_chrono::*;
+
+#[cfg(feature = "chrono-tz")]
+mod interop_chrono_tz {
+    use super::*;
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum FormatInTzError {
+        Unrepresentable { source: UnrepresentableChronoDateTimeError },
+        InvalidFormat,
+    }
+
+    impl fmt::Display for FormatInTzError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            use FormatInTzError::*;
+            match self {
+                Unrepresentable {
+                    source,
+                } => source.fmt(f),
+                InvalidFormat => f.write_str("format string contains an invalid or unsupported specifier"),
+            }
+        }
+    }
+
+    impl core::error::Error for FormatInTzError {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            use FormatInTzError::*;
+            match self {
+                Unrepresentable {
+                    source,
+                } => Some(source),
+                InvalidFormat => None,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+pub use interop_chrono_tz::*;
+
+#[cfg(feature = "httpdate")]
+mod interop_httpdate {
+    use super::*;
+    use std::time::UNIX_EPOCH;
+
+    macro_rules! impl_from_http_date {
+        ($($value:ty),+ $(,)?) => {
+            $(
+                impl<const POWER: i32> Timestamp<$value, POWER> {
+                    /// Parses an HTTP date (RFC 7231), e.g. `Tue, 15 Nov 1994 08:12:31 GMT`
+                    pub fn from_http_date(s: &str) -> Result<Self, FromHttpDateError> {
+                        use FromHttpDateError::*;
+                        let system_time = httpdate::parse_http_date(s).map_err(|source| InvalidHttpDate {
+                            source,
+                        })?;
+                        system_time
+                            .duration_since(UNIX_EPOCH)
+                            .ok()
+                            .and_then(|duration| exact_nanoseconds_to_timestamp_value(duration.as_nanos(), POWER))
+                            .and_then(|value| <$value>::try_from(value).ok())
+                            .map(Self::new)
+                            .ok_or(Unrepresentable)
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_from_http_date!(u32, u64, u128);
+
+    #[derive(Debug)]
+    pub enum FromHttpDateError {
+        InvalidHttpDate { source: httpdate::Error },
+        Unrepresentable,
+    }
+
+    impl fmt::Display for FromHttpDateError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            use FromHttpDateError::*;
+            match self {
+                InvalidHttpDate {
+                    source,
+                } => source.fmt(f),
+                Unrepresentable => f.write_str("HTTP date can't be represented exactly as a timestamp with this storage and power"),
+            }
+        }
+    }
+
+    impl core::error::Error for FromHttpDateError {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            use FromHttpDateError::*;
+            match self {
+                InvalidHttpDate {
+                    source,
+                } => Some(source),
+                Unrepresentable => None,
+            }
+        }
+    }
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct UnrepresentableHttpDateError;
+
+    impl fmt::Display for UnrepresentableHttpDateError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("timestamp is out of range for an HTTP date (years 1970 to 9999)")
+        }
+    }
+
+    impl core::error::Error for UnrepresentableHttpDateError {}
+}
+
+#[cfg(feature = "httpdate")]
+pub use interop_httpdate::*;
+
+#[cfg(feature = "serde_with")]
+mod interop_serde_with {
+    use super::*;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_with::{DeserializeAs, SerializeAs};
+
+    /// Allows `#[serde_as(as = "TimestampMilliseconds")]` on raw `u128` fields (the raw value is serialized as is)
+    impl<V: Serialize, const POWER: i32> SerializeAs<V> for Timestamp<V, POWER> {
+        #[inline]
+        fn serialize_as<S: Serializer>(source: &V, serializer: S) -> Result<S::Ok, S::Error> {
+            source.serialize(serializer)
+        }
+    }
+
+    impl<'de, V: Deserialize<'de>, const POWER: i32> DeserializeAs<'de, V> for Timestamp<V, POWER> {
+        #[inline]
+        fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<V, D::Error> {
+            V::deserialize(deserializer)
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+mod rand_jitter {
+    use super::*;
+    use rand::{Rng, RngCore};
+
+    macro_rules! impl_with_jitter {
+        ($($value:ty),+ $(,)?) => {
+            $(
+                impl<const POWER: i32> Timestamp<$value, POWER> {
+                    /// Adds a uniformly random offset in `[0, max_jitter]` (truncated to `POWER` units), saturating at the max value of the storage type (e.g. to spread scheduled tasks)
+                    #[inline]
+                    pub fn with_jitter(self, max_jitter: Duration, rng: &mut impl RngCore) -> Self {
+                        let max_units = duration_to_units::<POWER>(max_jitter).unwrap_or(u128::MAX);
+                        let offset = rng.random_range(0..=max_units);
+                        let value = u128::from(self.value).saturating_add(offset);
+                        Self::new(<$value>::try_from(value).unwrap_or(<$value>::MAX))
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_with_jitter!(u32, u64, u128);
+}
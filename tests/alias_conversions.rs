@@ -0,0 +1,150 @@
+use std::error::Error;
+use timestamp_please::{ScaleFailureReason, Timestamp, TimestampMicroseconds, TimestampMilliseconds, TimestampNanoseconds, TimestampSeconds, TimestampTryScaleError};
+
+#[test]
+fn try_into_converts_between_aliases_without_turbofish() -> Result<(), Box<dyn Error>> {
+    let secs = TimestampSeconds::new(1_700_000_000);
+    // widening the seconds is infallible, so it uses `From`
+    let millis: TimestampMilliseconds = secs.into();
+    assert_eq!(millis, TimestampMilliseconds::new(1_700_000_000_000));
+    let micros: TimestampMicroseconds = millis.try_into()?;
+    assert_eq!(micros, TimestampMicroseconds::new(1_700_000_000_000_000));
+    let secs_again: TimestampSeconds = micros.try_into()?;
+    assert_eq!(secs_again, secs);
+    Ok(())
+}
+
+#[test]
+fn try_into_rejects_inexact_and_overflowing_conversions() {
+    let inexact: Result<TimestampSeconds, _> = TimestampMilliseconds::new(1_500).try_into();
+    assert!(matches!(
+        inexact,
+        Err(TimestampTryScaleError::ScaleFailed {
+            reason: ScaleFailureReason::Inexact,
+            ..
+        })
+    ));
+    let overflow: Result<TimestampNanoseconds, _> = TimestampMilliseconds::new(u128::MAX).try_into();
+    assert!(matches!(
+        overflow,
+        Err(TimestampTryScaleError::ScaleFailed {
+            reason: ScaleFailureReason::ValueTooLarge,
+            ..
+        })
+    ));
+}
+
+const SAMPLES: [u128; 11] = [
+    0,
+    1,
+    999,
+    1_000,
+    1_500,
+    1_700_000_000,
+    1_700_000_000_123,
+    1_700_000_000_123_456_789,
+    u64::MAX as u128,
+    u128::MAX / 1_000,
+    u128::MAX,
+];
+
+/// Returns `value * 10^(power_in - power_out)` if it is a whole number that fits into `u128`
+fn exact_scale(value: u128, power_in: i32, power_out: i32) -> Option<u128> {
+    let factor = 10u128.checked_pow(power_in.abs_diff(power_out))?;
+    if power_in >= power_out {
+        value.checked_mul(factor)
+    } else {
+        (value % factor == 0).then_some(value / factor)
+    }
+}
+
+/// Same as [`exact_scale`], but truncates the digits below the target unit
+fn truncating_scale(value: u128, power_in: i32, power_out: i32) -> Option<u128> {
+    let factor = 10u128.checked_pow(power_in.abs_diff(power_out))?;
+    if power_in >= power_out { value.checked_mul(factor) } else { Some(value / factor) }
+}
+
+/// Checks `try_scale` (which keeps the storage type) and the trait conversion (`From` or `TryFrom`) into the target alias for every sample
+macro_rules! assert_alias_conversions {
+    ($($convert:ident: $from:ident($from_value:ty, $from_power:literal) => $into:ident($into_value:ty, $into_power:literal)),+ $(,)?) => {
+        $(
+            for sample in SAMPLES {
+                let Some(value) = <$from_value>::try_from(sample).ok() else { continue };
+                let from = $from::new(value);
+                let context = format!("{sample} from {} into {}", stringify!($from), stringify!($into));
+
+                let scaled = from.try_scale::<$into_power>().ok().map(|timestamp| u128::from(timestamp.into_value()));
+                let expected = truncating_scale(sample, $from_power, $into_power).filter(|&value| <$from_value>::try_from(value).is_ok());
+                assert_eq!(scaled, expected, "try_scale: {context}");
+
+                let converted = assert_alias_conversions!(@$convert from => $into).map(|timestamp| u128::from(timestamp.into_value()));
+                let expected = exact_scale(sample, $from_power, $into_power).filter(|&value| <$into_value>::try_from(value).is_ok());
+                assert_eq!(converted, expected, "{}: {context}", stringify!($convert));
+            }
+        )+
+    };
+    (@From $from:ident => $into:ident) => {
+        Some($into::from($from))
+    };
+    (@TryFrom $from:ident => $into:ident) => {
+        $into::try_from($from).ok()
+    };
+}
+
+#[test]
+fn every_ordered_pair_of_aliases_converts_as_documented() {
+    assert_alias_conversions!(
+        From: TimestampSeconds(u64, 0) => TimestampSeconds(u64, 0),
+        From: TimestampSeconds(u64, 0) => TimestampMilliseconds(u128, -3),
+        From: TimestampSeconds(u64, 0) => TimestampMicroseconds(u128, -6),
+        From: TimestampSeconds(u64, 0) => TimestampNanoseconds(u128, -9),
+        TryFrom: TimestampMilliseconds(u128, -3) => TimestampSeconds(u64, 0),
+        From: TimestampMilliseconds(u128, -3) => TimestampMilliseconds(u128, -3),
+        TryFrom: TimestampMilliseconds(u128, -3) => TimestampMicroseconds(u128, -6),
+        TryFrom: TimestampMilliseconds(u128, -3) => TimestampNanoseconds(u128, -9),
+        TryFrom: TimestampMicroseconds(u128, -6) => TimestampSeconds(u64, 0),
+        TryFrom: TimestampMicroseconds(u128, -6) => TimestampMilliseconds(u128, -3),
+        From: TimestampMicroseconds(u128, -6) => TimestampMicroseconds(u128, -6),
+        TryFrom: TimestampMicroseconds(u128, -6) => TimestampNanoseconds(u128, -9),
+        TryFrom: TimestampNanoseconds(u128, -9) => TimestampSeconds(u64, 0),
+        TryFrom: TimestampNanoseconds(u128, -9) => TimestampMilliseconds(u128, -3),
+        TryFrom: TimestampNanoseconds(u128, -9) => TimestampMicroseconds(u128, -6),
+        From: TimestampNanoseconds(u128, -9) => TimestampNanoseconds(u128, -9),
+    );
+}
+
+macro_rules! assert_widening_round_trips {
+    ($($convert:ident: $from:ident($from_value:ty) => $into:ident),+ $(,)?) => {
+        $(
+            for sample in SAMPLES {
+                let Some(value) = <$from_value>::try_from(sample).ok() else { continue };
+                let from = $from::new(value);
+                // a widening only fails if the finer value overflows the storage type
+                let Some(into) = assert_alias_conversions!(@$convert from => $into) else { continue };
+                assert_eq!($from::try_from(into).ok(), Some(from), "{sample} from {} into {}", stringify!($from), stringify!($into));
+            }
+        )+
+    };
+}
+
+#[test]
+fn widenings_round_trip_exactly() {
+    assert_widening_round_trips!(
+        From: TimestampSeconds(u64) => TimestampMilliseconds,
+        From: TimestampSeconds(u64) => TimestampMicroseconds,
+        From: TimestampSeconds(u64) => TimestampNanoseconds,
+        TryFrom: TimestampMilliseconds(u128) => TimestampMicroseconds,
+        TryFrom: TimestampMilliseconds(u128) => TimestampNanoseconds,
+        TryFrom: TimestampMicroseconds(u128) => TimestampNanoseconds,
+    );
+}
+
+#[test]
+fn try_scale_keeps_the_storage_type() {
+    // `TimestampSeconds` stores `u64`, so `try_scale` into milliseconds returns `Timestamp<u64, -3>` rather than `TimestampMilliseconds`
+    let scaled: Result<Timestamp<u64, -3>, _> = TimestampSeconds::new(1_700_000_000).try_scale::<-3>();
+    assert_eq!(scaled, Ok(Timestamp::new(1_700_000_000_000)));
+    assert_eq!(scaled.map(TimestampMilliseconds::from), Ok(TimestampMilliseconds::new(1_700_000_000_000)));
+    assert_eq!(TimestampMilliseconds::from(TimestampSeconds::new(u64::MAX)), TimestampMilliseconds::new(u128::from(u64::MAX) * 1_000));
+    assert!(TimestampSeconds::new(u64::MAX).try_scale::<-3>().is_err());
+}
@@ -0,0 +1,173 @@
+use core::cmp::Ordering;
+use core::time::Duration;
+use timestamp_please::{OverflowError, Timestamp, TimestampMilliseconds, TimestampNanoseconds, TimestampSeconds, earliest, latest};
+
+#[test]
+fn add_treats_rhs_as_an_offset() {
+    assert_eq!(TimestampMilliseconds::new(1_000) + TimestampMilliseconds::new(500), TimestampMilliseconds::new(1_500));
+    let mut timestamp = TimestampMilliseconds::new(1_000);
+    timestamp += TimestampMilliseconds::new(250);
+    assert_eq!(timestamp, TimestampMilliseconds::new(1_250));
+}
+
+#[test]
+fn add_saturates() {
+    assert_eq!(TimestampMilliseconds::new(u128::MAX - 1) + TimestampMilliseconds::new(2), TimestampMilliseconds::new(u128::MAX));
+    let mut timestamp = Timestamp::<i64, 0>::new(i64::MIN + 1);
+    timestamp += Timestamp::new(-2);
+    assert_eq!(timestamp, Timestamp::new(i64::MIN));
+    assert_eq!(TimestampSeconds::new(u64::MAX) + TimestampSeconds::new(1), TimestampSeconds::new(u64::MAX));
+}
+
+#[test]
+fn rem_units_returns_the_seconds_into_the_minute() {
+    assert_eq!(TimestampSeconds::new(1_700_000_000).rem_units(60), 20);
+    assert_eq!(TimestampSeconds::new(1_700_000_000).checked_rem_units(60), Some(20));
+    assert_eq!(TimestampMilliseconds::new(1_700_000_000_123).rem_units(60_000), 20_123);
+}
+
+#[test]
+fn checked_rem_units_rejects_a_zero_period() {
+    assert_eq!(TimestampSeconds::new(1_700_000_000).checked_rem_units(0), None);
+}
+
+#[test]
+fn lerp_returns_the_endpoints() {
+    let (start, end) = (TimestampMilliseconds::new(1_000), TimestampMilliseconds::new(2_000));
+    assert_eq!(start.lerp(end, 0.0), start);
+    assert_eq!(start.lerp(end, 1.0), end);
+    assert_eq!(start.lerp(end, -1.0), start);
+    assert_eq!(start.lerp(end, 2.0), end);
+    assert_eq!(start.lerp(end, f64::NAN), start);
+}
+
+#[test]
+fn lerp_returns_the_midpoint() {
+    let (start, end) = (TimestampMilliseconds::new(1_000), TimestampMilliseconds::new(2_000));
+    assert_eq!(start.lerp(end, 0.5), TimestampMilliseconds::new(1_500));
+    assert_eq!(end.lerp(start, 0.5), TimestampMilliseconds::new(1_500));
+    assert_eq!(start.lerp(end, 0.25), TimestampMilliseconds::new(1_250));
+}
+
+#[test]
+fn lerp_rounding_does_not_depend_on_the_argument_order() {
+    let (a, b) = (TimestampMilliseconds::new(0), TimestampMilliseconds::new(15));
+    assert_eq!(a.lerp(b, 0.5), TimestampMilliseconds::new(8));
+    assert_eq!(b.lerp(a, 0.5), TimestampMilliseconds::new(8));
+    [0.125, 0.25, 0.5, 0.75, 0.875]
+        .into_iter()
+        .for_each(|t| assert_eq!(a.lerp(b, t), b.lerp(a, 1.0 - t), "t = {t}"));
+}
+
+#[test]
+fn latest_and_earliest_of_an_empty_iterator_are_none() {
+    assert_eq!(latest(core::iter::empty::<TimestampSeconds>()), None);
+    assert_eq!(earliest(Vec::<TimestampSeconds>::new()), None);
+}
+
+#[test]
+fn latest_and_earliest_pick_the_extremes() {
+    let timestamps = [1_700_000_300, 1_700_000_000, 1_700_000_900, 1_700_000_600].map(TimestampSeconds::new);
+    assert_eq!(latest(timestamps), Some(TimestampSeconds::new(1_700_000_900)));
+    assert_eq!(earliest(timestamps), Some(TimestampSeconds::new(1_700_000_000)));
+}
+
+#[test]
+fn sub_returns_positive_and_negative_differences() {
+    let (earlier, later) = (Timestamp::<i64, -3>::new(1_000), Timestamp::<i64, -3>::new(1_500));
+    assert_eq!(later - earlier, 500);
+    assert_eq!(earlier - later, -500);
+    assert_eq!(Timestamp::<i64, 0>::new(-5) - Timestamp::new(5), -10);
+}
+
+#[test]
+fn checked_signed_diff_returns_none_on_overflow() {
+    assert_eq!(Timestamp::<i64, 0>::new(i64::MIN).checked_signed_diff(Timestamp::new(1)), None);
+    assert_eq!(Timestamp::<i64, 0>::new(1).checked_signed_diff(Timestamp::new(-1)), Some(2));
+}
+
+#[test]
+#[should_panic(expected = "attempt to subtract with overflow")]
+fn sub_panics_on_overflow() {
+    let _ = Timestamp::<i64, 0>::new(i64::MAX) - Timestamp::new(-1);
+}
+
+#[test]
+fn checked_offset_nudges_in_both_directions() {
+    assert_eq!(TimestampSeconds::new(1_700_000_000).checked_offset(1), Some(TimestampSeconds::new(1_700_000_001)));
+    assert_eq!(TimestampSeconds::new(1_700_000_000).checked_offset(-1), Some(TimestampSeconds::new(1_699_999_999)));
+}
+
+#[test]
+fn checked_offset_returns_none_on_underflow_below_zero() {
+    assert_eq!(TimestampSeconds::new(0).checked_offset(-1), None);
+    assert_eq!(TimestampSeconds::new(u64::MAX).checked_offset(1), None);
+}
+
+#[test]
+fn diff_units_returns_the_absolute_unit_delta() {
+    assert_eq!(TimestampMilliseconds::new(1_500).diff_units(TimestampMilliseconds::new(1_000)), 500);
+    assert_eq!(TimestampMilliseconds::new(1_000).diff_units(TimestampMilliseconds::new(1_500)), 500);
+    assert_eq!(TimestampNanoseconds::new(0).diff_units(TimestampNanoseconds::new(u128::MAX)), u128::MAX);
+}
+
+#[test]
+fn cmp_approx_treats_timestamps_within_the_tolerance_as_equal() {
+    let timestamp = TimestampMilliseconds::new(1_000);
+    let tolerance = Duration::from_millis(5);
+    assert_eq!(timestamp.cmp_approx(&TimestampMilliseconds::new(1_005), tolerance), Ordering::Equal);
+    assert_eq!(timestamp.cmp_approx(&TimestampMilliseconds::new(995), tolerance), Ordering::Equal);
+    assert_eq!(timestamp.cmp_approx(&TimestampMilliseconds::new(1_006), tolerance), Ordering::Less);
+    assert_eq!(timestamp.cmp_approx(&TimestampMilliseconds::new(994), tolerance), Ordering::Greater);
+}
+
+#[test]
+fn div_truncates_the_unit_count() {
+    assert_eq!(TimestampSeconds::new(10) / 2, TimestampSeconds::new(5));
+    assert_eq!(TimestampSeconds::new(10) / 3, TimestampSeconds::new(3));
+}
+
+#[test]
+fn epoch_predicates_classify_signed_values() {
+    let predicates = |timestamp: Timestamp<i64, 0>| (timestamp.is_before_epoch(), timestamp.is_epoch(), timestamp.is_after_epoch());
+    assert_eq!(predicates(Timestamp::new(-1)), (true, false, false));
+    assert_eq!(predicates(Timestamp::new(0)), (false, true, false));
+    assert_eq!(predicates(Timestamp::new(1)), (false, false, true));
+}
+
+#[test]
+fn try_add_returns_an_error_on_overflow() {
+    assert_eq!(TimestampSeconds::new(1).try_add(TimestampSeconds::new(2)), Ok(TimestampSeconds::new(3)));
+    assert_eq!(TimestampSeconds::new(u64::MAX).try_add(TimestampSeconds::new(1)), Err(OverflowError::Overflow));
+    assert_eq!(Timestamp::<i64, 0>::new(i64::MIN).try_add(Timestamp::new(-1)), Err(OverflowError::Underflow));
+}
+
+#[test]
+fn try_sub_returns_an_error_on_underflow_below_zero() {
+    assert_eq!(TimestampSeconds::new(3).try_sub(TimestampSeconds::new(2)), Ok(TimestampSeconds::new(1)));
+    assert_eq!(TimestampSeconds::new(0).try_sub(TimestampSeconds::new(1)), Err(OverflowError::Underflow));
+    assert_eq!(Timestamp::<i64, 0>::new(i64::MAX).try_sub(Timestamp::new(-1)), Err(OverflowError::Overflow));
+}
+
+#[test]
+fn bump_monotonic_advances_by_one_unit_when_now_is_not_later() {
+    let mut timestamp = TimestampMilliseconds::new(1_000);
+    timestamp.bump_monotonic(TimestampMilliseconds::new(999));
+    assert_eq!(timestamp, TimestampMilliseconds::new(1_001));
+    timestamp.bump_monotonic(TimestampMilliseconds::new(1_001));
+    assert_eq!(timestamp, TimestampMilliseconds::new(1_002));
+}
+
+#[test]
+fn bump_monotonic_jumps_to_a_later_now() {
+    let mut timestamp = TimestampMilliseconds::new(1_000);
+    timestamp.bump_monotonic(TimestampMilliseconds::new(2_000));
+    assert_eq!(timestamp, TimestampMilliseconds::new(2_000));
+}
+
+#[test]
+fn bump_monotonic_saturates() {
+    let mut timestamp = TimestampSeconds::new(u64::MAX);
+    timestamp.bump_monotonic(TimestampSeconds::new(0));
+    assert_eq!(timestamp, TimestampSeconds::new(u64::MAX));
+}
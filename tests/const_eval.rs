@@ -0,0 +1,41 @@
+use timestamp_please::{Timestamp, TimestampMicroseconds, TimestampMilliseconds, TimestampNanoseconds, TimestampSeconds, pow10_u128, scale_u128, unit_name};
+
+const SECONDS: TimestampSeconds = Timestamp::seconds(5);
+const MILLIS: TimestampMilliseconds = Timestamp::millis(5);
+const MICROS: TimestampMicroseconds = Timestamp::micros(5);
+const NANOS: TimestampNanoseconds = Timestamp::nanos(5);
+
+const _: () = assert!(MILLIS.value() == 5);
+const _: () = assert!(matches!(pow10_u128(3), Some(1_000)));
+const _: () = assert!(pow10_u128(39).is_none());
+const _: () = assert!(matches!(scale_u128(MILLIS.value(), 6), Some(5_000_000)));
+const _: () = assert!(matches!(scale_u128(1_500, -3), Some(1)));
+
+const UNIT_NAMES: [&str; 5] = [
+    unit_name(0),
+    unit_name(-3),
+    unit_name(-6),
+    unit_name(-9),
+    unit_name(-7),
+];
+const _: () = assert!(unit_name(-7).is_empty());
+
+static STARTUP: TimestampSeconds = Timestamp::seconds(1_700_000_000);
+
+#[test]
+fn unit_named_constructors_fix_the_power() {
+    assert_eq!(SECONDS, TimestampSeconds::new(5));
+    assert_eq!(MILLIS, TimestampMilliseconds::new(5));
+    assert_eq!(MICROS, TimestampMicroseconds::new(5));
+    assert_eq!(NANOS, TimestampNanoseconds::new(5));
+}
+
+#[test]
+fn const_helpers_are_usable_in_static_initializers() {
+    assert_eq!(STARTUP.value(), 1_700_000_000);
+}
+
+#[test]
+fn unit_name_is_usable_in_const_bindings() {
+    assert_eq!(UNIT_NAMES, ["s", "ms", "us", "ns", ""]);
+}
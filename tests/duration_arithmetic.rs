@@ -0,0 +1,209 @@
+use core::time::Duration;
+use timestamp_please::{OverflowError, Timestamp, TimestampMilliseconds, TimestampNanoseconds, TimestampSeconds, duration_between, signed_units_between};
+
+#[test]
+fn quantize_rounds_down_to_the_minute() {
+    assert_eq!(TimestampSeconds::new(1_700_000_059).quantize(Duration::from_secs(60)), Some(TimestampSeconds::new(1_700_000_040)));
+    assert_eq!(TimestampMilliseconds::new(1_700_000_059_999).quantize(Duration::from_secs(60)), Some(TimestampMilliseconds::new(1_700_000_040_000)));
+    assert_eq!(TimestampSeconds::new(1_700_000_040).quantize(Duration::from_secs(60)), Some(TimestampSeconds::new(1_700_000_040)));
+}
+
+#[test]
+fn quantize_rounds_down_to_the_millisecond() {
+    assert_eq!(TimestampNanoseconds::new(1_700_000_000_123_456_789).quantize(Duration::from_millis(1)), Some(TimestampNanoseconds::new(1_700_000_000_123_000_000)));
+    assert_eq!(TimestampMilliseconds::new(1_700_000_000_123).quantize(Duration::from_millis(1)), Some(TimestampMilliseconds::new(1_700_000_000_123)));
+}
+
+#[test]
+fn quantize_keeps_values_beyond_u128_nanoseconds() {
+    let max = TimestampMilliseconds::new(u128::MAX);
+    assert_eq!(max.quantize(Duration::from_secs(60)), Some(TimestampMilliseconds::new(u128::MAX - u128::MAX % 60_000)));
+}
+
+#[test]
+fn quantize_rejects_resolutions_that_are_not_whole_units() {
+    // 1.5s is not a whole number of seconds, so most of its multiples (e.g. 10.5s) aren't representable
+    assert_eq!(TimestampSeconds::new(11).quantize(Duration::from_millis(1_500)), None);
+    assert_eq!(Timestamp::<u64, 3>::new(7).quantize(Duration::from_secs(1)), None);
+    assert_eq!(TimestampSeconds::new(10).quantize(Duration::ZERO), None);
+    assert_eq!(TimestampMilliseconds::new(11_000).quantize(Duration::from_millis(1_500)), Some(TimestampMilliseconds::new(10_500)));
+}
+
+#[test]
+fn quantize_to_a_resolution_beyond_the_storage_returns_zero() {
+    assert_eq!(Timestamp::<u128, -30>::new(u128::MAX).quantize(Duration::MAX), Some(Timestamp::new(0)));
+}
+
+#[test]
+fn next_aligned_returns_the_next_quarter_hour() {
+    let period = Duration::from_secs(15 * 60);
+    assert_eq!(TimestampSeconds::new(1_700_000_000).next_aligned(period), Some(TimestampSeconds::new(1_700_000_100)));
+    assert_eq!(TimestampSeconds::new(1_700_000_100).next_aligned(period), Some(TimestampSeconds::new(1_700_001_000)));
+    assert_eq!(TimestampMilliseconds::new(1_700_000_000_001).next_aligned(period), Some(TimestampMilliseconds::new(1_700_000_100_000)));
+}
+
+#[test]
+fn next_aligned_rejects_zero_period_and_overflow() {
+    assert_eq!(TimestampSeconds::new(1_700_000_000).next_aligned(Duration::ZERO), None);
+    assert_eq!(TimestampSeconds::new(u64::MAX).next_aligned(Duration::from_secs(15 * 60)), None);
+}
+
+#[test]
+fn rem_duration_returns_the_time_since_the_top_of_the_hour() {
+    let hour = Duration::from_secs(3_600);
+    assert_eq!(TimestampSeconds::new(1_700_000_000) % hour, Duration::from_secs(800));
+    assert_eq!(TimestampMilliseconds::new(1_700_000_000_123) % hour, Duration::from_millis(800_123));
+    assert_eq!(TimestampSeconds::new(1_699_999_200) % hour, Duration::ZERO);
+    assert_eq!(TimestampSeconds::new(1_700_000_000).checked_rem_duration(hour), Some(Duration::from_secs(800)));
+}
+
+#[test]
+fn rem_duration_handles_timestamps_beyond_u128_nanoseconds() {
+    assert_eq!(TimestampMilliseconds::new(u128::MAX) % Duration::from_secs(60), Duration::from_millis(31_455));
+    // 7.000000001s is not a whole number of the unit, so the remainder is computed modulo the period in nanoseconds
+    assert_eq!(Timestamp::<u128, 3>::new(u128::MAX) % Duration::new(7, 1), Duration::from_nanos(703_189_388));
+    assert_eq!(TimestampSeconds::new(10) % Duration::from_millis(1_500), Duration::from_secs(1));
+}
+
+#[test]
+fn checked_rem_duration_rejects_sub_nanosecond_digits() {
+    assert_eq!(Timestamp::<u128, -12>::new(61_000_000_001_000) % Duration::from_secs(60), Duration::from_nanos(1_000_000_001));
+    assert_eq!(Timestamp::<u128, -12>::new(61_000_000_001_500).checked_rem_duration(Duration::from_secs(60)), None);
+    assert_eq!(Timestamp::<u128, -40>::new(u128::MAX).checked_rem_duration(Duration::from_secs(1)), None);
+    // the digits that are multiples of the period don't matter
+    assert_eq!(Timestamp::<u128, -12>::new(61_500).checked_rem_duration(Duration::from_nanos(1)), None);
+    assert_eq!(Timestamp::<u128, -12>::new(60_500_000_000_000).checked_rem_duration(Duration::from_secs(60)), Some(Duration::from_millis(500)));
+}
+
+#[test]
+fn checked_rem_duration_rejects_a_zero_period() {
+    assert_eq!(TimestampSeconds::new(1_700_000_000).checked_rem_duration(Duration::ZERO), None);
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with a zero duration")]
+fn rem_duration_panics_on_a_zero_period() {
+    let _ = TimestampSeconds::new(1_700_000_000) % Duration::ZERO;
+}
+
+#[test]
+fn duration_between_mixes_milliseconds_and_nanoseconds() {
+    let ms = TimestampMilliseconds::new(1_700_000_000_123);
+    let ns = TimestampNanoseconds::new(1_700_000_000_000_000_001);
+    assert_eq!(duration_between(ms, ns), Some(Duration::from_nanos(122_999_999)));
+    assert_eq!(duration_between(ns, ms), Some(Duration::from_nanos(122_999_999)));
+    assert_eq!(duration_between(ms, TimestampNanoseconds::new(1_700_000_000_123_000_000)), Some(Duration::ZERO));
+}
+
+#[test]
+fn duration_between_returns_none_on_overflow() {
+    assert_eq!(duration_between(TimestampMilliseconds::new(u128::MAX), TimestampNanoseconds::new(0)), None);
+}
+
+#[test]
+fn from_base_plus_adds_an_hour() {
+    let hour = Duration::from_secs(3_600);
+    assert_eq!(TimestampSeconds::from_base_plus(TimestampSeconds::new(1_700_000_000), hour), Some(TimestampSeconds::new(1_700_003_600)));
+    assert_eq!(TimestampMilliseconds::from_base_plus(TimestampMilliseconds::new(1_700_000_000_123), hour), Some(TimestampMilliseconds::new(1_700_003_600_123)));
+    assert_eq!(TimestampSeconds::new(1_700_000_000).checked_add_duration(hour), Some(TimestampSeconds::new(1_700_003_600)));
+}
+
+#[test]
+fn from_base_plus_returns_none_on_overflow() {
+    assert_eq!(TimestampSeconds::from_base_plus(TimestampSeconds::new(u64::MAX), Duration::from_secs(1)), None);
+}
+
+#[test]
+fn approx_eq_accepts_the_exact_tolerance() {
+    let tolerance = Duration::from_millis(500);
+    let timestamp = TimestampMilliseconds::new(1_700_000_000_000);
+    assert!(timestamp.approx_eq(&TimestampMilliseconds::new(1_700_000_000_500), tolerance));
+    assert!(timestamp.approx_eq(&TimestampMilliseconds::new(1_699_999_999_500), tolerance));
+    assert!(timestamp.approx_eq(&timestamp, Duration::ZERO));
+}
+
+#[test]
+fn approx_eq_rejects_beyond_the_tolerance() {
+    let tolerance = Duration::from_millis(500);
+    let timestamp = TimestampMilliseconds::new(1_700_000_000_000);
+    assert!(!timestamp.approx_eq(&TimestampMilliseconds::new(1_700_000_000_501), tolerance));
+    assert!(!timestamp.approx_eq(&TimestampMilliseconds::new(1_699_999_999_499), tolerance));
+    // the tolerance is floored to whole seconds
+    assert!(!TimestampSeconds::new(10).approx_eq(&TimestampSeconds::new(12), Duration::from_millis(1_999)));
+}
+
+#[test]
+fn signed_units_between_is_positive_when_the_first_timestamp_is_later() {
+    let seconds = Timestamp::<u128, 0>::new(2);
+    let millis = TimestampMilliseconds::new(1_500);
+    assert_eq!(signed_units_between(seconds, millis), Some(500_000_000));
+    assert_eq!(signed_units_between(millis, seconds), Some(-500_000_000));
+    assert_eq!(signed_units_between(millis, TimestampNanoseconds::new(1_500_000_000)), Some(0));
+}
+
+#[test]
+fn intervals_between_counts_whole_periods() {
+    let quarter = Duration::from_secs(15 * 60);
+    let start = TimestampSeconds::new(1_700_000_000);
+    assert_eq!(start.intervals_between(TimestampSeconds::new(1_700_003_600), quarter), Some(4));
+    // the partial interval is not counted
+    assert_eq!(start.intervals_between(TimestampSeconds::new(1_700_001_000), quarter), Some(1));
+    assert_eq!(start.intervals_between(TimestampSeconds::new(1_700_000_899), quarter), Some(0));
+}
+
+#[test]
+fn nanosecond_differences_reject_sub_nanosecond_digits() {
+    let picos = Timestamp::<u128, -12>::new(1_500);
+    assert_eq!(duration_between(picos, TimestampNanoseconds::new(0)), None);
+    assert_eq!(signed_units_between(picos, TimestampNanoseconds::new(0)), None);
+    let picos = Timestamp::<u128, -12>::new(2_000);
+    assert_eq!(duration_between(picos, TimestampNanoseconds::new(0)), Some(Duration::from_nanos(2)));
+    assert_eq!(signed_units_between(TimestampNanoseconds::new(0), picos), Some(-2));
+}
+
+#[test]
+fn intervals_between_divides_the_gap_without_truncation() {
+    let start = Timestamp::<u128, -12>::new(0);
+    assert_eq!(start.intervals_between(Timestamp::new(2_999), Duration::from_nanos(1)), Some(2));
+    assert_eq!(start.intervals_between(Timestamp::new(3_000), Duration::from_nanos(1)), Some(3));
+    // the gap exceeds `u128::MAX` nanoseconds, but the period is a whole number of units
+    let start = Timestamp::<u128, 0>::new(0);
+    assert_eq!(start.intervals_between(Timestamp::new(u128::MAX), Duration::from_secs(1)), Some(u128::MAX));
+    // the period exceeds `u128::MAX` units, so no interval fits
+    let start = Timestamp::<u128, -30>::new(0);
+    assert_eq!(start.intervals_between(Timestamp::new(u128::MAX), Duration::from_secs(u64::MAX)), Some(0));
+}
+
+#[test]
+fn intervals_between_rejects_a_zero_period_and_a_reversed_range() {
+    let start = TimestampSeconds::new(1_700_000_000);
+    assert_eq!(start.intervals_between(TimestampSeconds::new(1_700_003_600), Duration::ZERO), None);
+    assert_eq!(TimestampSeconds::new(1_700_003_600).intervals_between(start, Duration::from_secs(60)), None);
+}
+
+#[test]
+fn try_add_assign_duration_leaves_the_value_untouched_on_overflow() {
+    let mut timestamp = TimestampSeconds::new(u64::MAX - 1);
+    assert_eq!(timestamp.try_add_assign_duration(Duration::from_secs(1)), Ok(()));
+    assert_eq!(timestamp, TimestampSeconds::new(u64::MAX));
+    assert_eq!(timestamp.try_add_assign_duration(Duration::from_secs(1)), Err(OverflowError::Overflow));
+    assert_eq!(timestamp, TimestampSeconds::new(u64::MAX));
+}
+
+#[test]
+fn try_sub_assign_duration_leaves_the_value_untouched_on_underflow() {
+    let mut timestamp = TimestampMilliseconds::new(1_000);
+    assert_eq!(timestamp.try_sub_assign_duration(Duration::from_millis(1_001)), Err(OverflowError::Underflow));
+    assert_eq!(timestamp, TimestampMilliseconds::new(1_000));
+    assert_eq!(timestamp.try_sub_assign_duration(Duration::from_secs(1)), Ok(()));
+    assert_eq!(timestamp, TimestampMilliseconds::new(0));
+}
+
+#[test]
+fn checked_add_same_unit_keeps_sub_nanosecond_digits() {
+    let timestamp = Timestamp::<u64, -12>::new(1_000);
+    assert_eq!(timestamp.checked_add_same_unit(Timestamp::new(500)), Some(Timestamp::new(1_500)));
+    // the `Duration` path can only add whole nanoseconds
+    assert_eq!(timestamp.checked_add_duration(Duration::from_nanos(1)), Some(Timestamp::new(2_000)));
+    assert_eq!(Timestamp::<u64, -12>::new(u64::MAX).checked_add_same_unit(Timestamp::new(1)), None);
+}
@@ -0,0 +1,174 @@
+use core::time::Duration;
+use timestamp_please::{Timestamp, TimestampMilliseconds, TimestampNanoseconds, TimestampSeconds, ToDurationError, checked_nanoseconds_to_duration, duration_to_units, nanoseconds_to_duration};
+
+#[test]
+fn timestamp_equals_duration_since_epoch() {
+    assert_eq!(TimestampSeconds::new(1_700_000_000), Duration::from_secs(1_700_000_000));
+    assert_eq!(TimestampMilliseconds::new(1_700_000_000_123), Duration::from_millis(1_700_000_000_123));
+}
+
+#[test]
+fn timestamp_differs_from_other_duration() {
+    assert_ne!(TimestampSeconds::new(1_700_000_000), Duration::from_secs(1_700_000_001));
+    assert_ne!(TimestampMilliseconds::new(1_500), Duration::from_secs(1));
+    // the raw value is not compared
+    assert_ne!(TimestampMilliseconds::new(1_500), Duration::from_secs(1_500));
+}
+
+#[test]
+fn timestamp_beyond_duration_max_never_equals_duration() {
+    assert_ne!(Timestamp::<u128, 9>::new(u128::MAX), Duration::MAX);
+}
+
+#[test]
+fn saturating_from_duration_clamps_at_the_storage_max() {
+    let max = Duration::from_secs(u64::from(u32::MAX));
+    assert_eq!(Timestamp::<u32, 0>::saturating_from_duration(max), u32::MAX);
+    assert_eq!(Timestamp::<u32, 0>::saturating_from_duration(max - Duration::from_secs(1)), u32::MAX - 1);
+    assert_eq!(Timestamp::<u32, 0>::saturating_from_duration(max + Duration::from_secs(1)), u32::MAX);
+    assert_eq!(Timestamp::<u64, -9>::saturating_from_duration(Duration::MAX), u64::MAX);
+}
+
+#[test]
+fn duration_to_units_converts_into_several_powers() {
+    let duration = Duration::new(1_700_000_000, 123_456_789);
+    assert_eq!(duration_to_units::<3>(duration), Some(1_700_000));
+    assert_eq!(duration_to_units::<0>(duration), Some(1_700_000_000));
+    assert_eq!(duration_to_units::<-3>(duration), Some(1_700_000_000_123));
+    assert_eq!(duration_to_units::<-9>(duration), Some(1_700_000_000_123_456_789));
+    assert_eq!(duration_to_units::<-12>(duration), Some(1_700_000_000_123_456_789_000));
+}
+
+#[test]
+fn duration_to_units_returns_none_on_overflow() {
+    assert_eq!(duration_to_units::<-30>(Duration::MAX), None);
+    assert_eq!(duration_to_units::<-30>(Duration::ZERO), Some(0));
+}
+
+#[test]
+fn checked_to_duration_handles_large_positive_powers() {
+    assert_eq!(Timestamp::<u64, 6>::new(1_700).checked_to_duration(), Some(Duration::from_secs(1_700_000_000)));
+    assert_eq!(Timestamp::<u64, 9>::new(18_446_744_073).checked_to_duration(), Some(Duration::from_secs(18_446_744_073_000_000_000)));
+    assert_eq!(Timestamp::<u64, 12>::new(18_446_744).checked_to_duration(), Some(Duration::from_secs(18_446_744_000_000_000_000)));
+}
+
+#[test]
+fn checked_to_duration_detects_the_saturation_of_large_positive_powers() {
+    assert_eq!(Timestamp::<u64, 6>::new(u64::MAX).checked_to_duration(), None);
+    assert_eq!(Timestamp::<u64, 9>::new(18_446_744_074).checked_to_duration(), None);
+    assert_eq!(Timestamp::<u64, 12>::new(18_446_745).checked_to_duration(), None);
+    assert_eq!(Timestamp::<u64, 12>::new(18_446_745).to_duration(), Some(Duration::MAX));
+}
+
+#[test]
+fn unix_secs_truncates_milliseconds_and_nanoseconds() {
+    assert_eq!(TimestampMilliseconds::new(1_700_000_000_999).unix_secs(), 1_700_000_000);
+    assert_eq!(TimestampNanoseconds::new(1_700_000_000_999_999_999).unix_secs(), 1_700_000_000);
+    assert_eq!(TimestampNanoseconds::new(999_999_999).unix_secs(), 0);
+}
+
+#[test]
+fn unix_secs_saturates_at_u64_max() {
+    assert_eq!(TimestampMilliseconds::new(u128::MAX).unix_secs(), u64::MAX);
+}
+
+#[test]
+fn to_duration_saturating_clamps_at_duration_max() {
+    assert_eq!(TimestampSeconds::new(u64::MAX).to_duration_saturating(), Some(Duration::from_secs(u64::MAX)));
+    let max_ns = Duration::MAX.as_nanos();
+    assert_eq!(TimestampNanoseconds::new(max_ns).to_duration_saturating(), Some(Duration::MAX));
+    assert_eq!(TimestampNanoseconds::new(max_ns + 1).to_duration_saturating(), Some(Duration::MAX));
+    assert_eq!(Timestamp::<u128, 0>::new(u128::from(u64::MAX) + 1).to_duration_saturating(), Some(Duration::MAX));
+    assert_eq!(TimestampNanoseconds::new(max_ns - 1).to_duration_saturating(), Some(Duration::MAX - Duration::from_nanos(1)));
+}
+
+#[test]
+fn nanoseconds_to_duration_handles_u128_max() {
+    assert_eq!(checked_nanoseconds_to_duration(u128::MAX), None);
+    assert_eq!(nanoseconds_to_duration(u128::MAX), Duration::MAX);
+}
+
+#[test]
+fn nanoseconds_to_duration_handles_the_u64_max_second_boundary() {
+    let last = u128::from(u64::MAX) * 1_000_000_000 + 999_999_999;
+    assert_eq!(checked_nanoseconds_to_duration(last), Some(Duration::MAX));
+    assert_eq!(nanoseconds_to_duration(last), Duration::MAX);
+    let next_second = u128::from(u64::MAX) * 1_000_000_000 + 1_000_000_000;
+    assert_eq!(checked_nanoseconds_to_duration(next_second), None);
+    assert_eq!(nanoseconds_to_duration(next_second), Duration::MAX);
+}
+
+#[test]
+fn as_unix_millis_rescales_seconds_and_nanoseconds() {
+    assert_eq!(TimestampSeconds::new(1_700_000_000).as_unix_millis(), 1_700_000_000_000);
+    assert_eq!(TimestampNanoseconds::new(1_700_000_000_123_999_999).as_unix_millis(), 1_700_000_000_123);
+    assert_eq!(Timestamp::<u128, 0>::new(u128::MAX).as_unix_millis(), u128::MAX);
+}
+
+#[test]
+fn timestamp_with_sub_nanosecond_digits_never_equals_duration() {
+    assert_ne!(Timestamp::<u64, -12>::new(500), Duration::ZERO);
+    assert_ne!(Timestamp::<u64, -12>::new(1_500), Duration::from_nanos(1));
+    assert_eq!(Timestamp::<u64, -12>::new(1_000), Duration::from_nanos(1));
+}
+
+#[test]
+fn try_to_duration_rejects_sub_nanosecond_digits() {
+    assert_eq!(Timestamp::<u64, -12>::new(1_000).try_to_duration(), Ok(Duration::from_nanos(1)));
+    assert_eq!(Timestamp::<u64, -12>::new(1_500).try_to_duration(), Err(ToDurationError::SubNanosecondDigits));
+    assert_eq!(Timestamp::<u64, -12>::new(1_500).checked_to_duration(), None);
+    assert_eq!(Timestamp::<u64, -12>::new(1_500).to_duration(), None);
+    assert_eq!(Timestamp::<u64, -12>::new(1_500).to_duration_saturating(), None);
+    // the whole seconds are still available
+    assert_eq!(Timestamp::<u64, -12>::new(2_000_000_000_500).unix_secs(), 2);
+    assert_eq!(Timestamp::<u128, 9>::new(u128::MAX).try_to_duration(), Err(ToDurationError::TooLarge));
+}
+
+#[test]
+fn to_unix_secs_i64_converts_a_normal_value() {
+    assert_eq!(TimestampMilliseconds::new(1_700_000_000_999).to_unix_secs_i64(), Some(1_700_000_000));
+    assert_eq!(Timestamp::<i64, -3>::new(-1_500).to_unix_secs_i64(), Some(-1));
+}
+
+#[test]
+fn to_unix_secs_i64_returns_none_beyond_i64_max() {
+    assert_eq!(TimestampSeconds::new(i64::MAX as u64).to_unix_secs_i64(), Some(i64::MAX));
+    assert_eq!(TimestampSeconds::new(i64::MAX as u64 + 1).to_unix_secs_i64(), None);
+    assert_eq!(Timestamp::<u64, 9>::new(10_000_000_000).to_unix_secs_i64(), None);
+}
+
+#[test]
+fn unix_days_floors_toward_negative_infinity() {
+    assert_eq!(TimestampSeconds::new(0).unix_days(), 0);
+    assert_eq!(TimestampSeconds::new(86_399).unix_days(), 0);
+    // day 1 at 23:59
+    assert_eq!(TimestampSeconds::new(2 * 86_400 - 60).unix_days(), 1);
+    assert_eq!(TimestampMilliseconds::new(172_740_000).unix_days(), 1);
+    assert_eq!(Timestamp::<i64, 0>::new(-1).unix_days(), -1);
+    assert_eq!(Timestamp::<i64, -3>::new(-86_400_001).unix_days(), -2);
+}
+
+#[test]
+fn clamped_to_representable_duration_clamps_a_huge_value() {
+    let max_ns = Duration::MAX.as_nanos();
+    assert_eq!(TimestampNanoseconds::new(u128::MAX).clamped_to_representable_duration(), TimestampNanoseconds::new(max_ns));
+    assert_eq!(TimestampMilliseconds::new(u128::MAX).clamped_to_representable_duration(), TimestampMilliseconds::new(max_ns / 1_000_000));
+    let clamped = Timestamp::<u128, 0>::new(u128::MAX).clamped_to_representable_duration();
+    assert_eq!(clamped, Timestamp::new(u128::from(u64::MAX)));
+    assert_eq!(clamped.checked_to_duration(), Some(Duration::from_secs(u64::MAX)));
+}
+
+#[test]
+fn clamped_to_representable_duration_keeps_a_representable_value() {
+    assert_eq!(TimestampMilliseconds::new(1_700_000_000_123).clamped_to_representable_duration(), TimestampMilliseconds::new(1_700_000_000_123));
+    // every `u64` count of seconds fits into `Duration`
+    assert_eq!(TimestampSeconds::new(u64::MAX).clamped_to_representable_duration(), TimestampSeconds::new(u64::MAX));
+}
+
+#[test]
+fn seconds_and_subsecond_units_splits_at_the_second_boundary() {
+    assert_eq!(TimestampMilliseconds::new(1_700_000_000_123).seconds_and_subsecond_units(), (1_700_000_000, 123));
+    assert_eq!(TimestampNanoseconds::new(1_700_000_000_123_456_789).seconds_and_subsecond_units(), (1_700_000_000, 123_456_789));
+    assert_eq!(TimestampNanoseconds::new(999_999_999).seconds_and_subsecond_units(), (0, 999_999_999));
+    assert_eq!(TimestampSeconds::new(1_700_000_000).seconds_and_subsecond_units(), (1_700_000_000, 0));
+}
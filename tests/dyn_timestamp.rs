@@ -0,0 +1,42 @@
+use timestamp_please::{DynTimestamp, Timestamp, TimestampMilliseconds, TimestampNanoseconds};
+
+#[test]
+fn dyn_timestamps_render_in_fixed_point_seconds() {
+    let timestamps = [
+        DynTimestamp::from(Timestamp::<u128, 0>::new(1_700_000_000)),
+        DynTimestamp::from(TimestampMilliseconds::new(1_700_000_000_123)),
+        DynTimestamp::from(TimestampNanoseconds::new(1_700_000_000_000_000_001)),
+    ];
+    let rendered = timestamps
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(rendered, ["1700000000", "1700000000.123", "1700000000.000000001"]);
+}
+
+#[test]
+fn dyn_timestamp_keeps_the_power() {
+    let timestamp = TimestampMilliseconds::new(1_500).to_dyn();
+    assert_eq!(timestamp.power(), -3);
+    assert_eq!(timestamp.into_value(), 1_500);
+    assert_eq!(DynTimestamp::new(5u64, -9).to_string(), "0.000000005");
+}
+
+#[test]
+fn parse_parses_the_same_digits_at_different_powers() {
+    assert_eq!(DynTimestamp::parse("1700000000123", -3), Ok(DynTimestamp::new(1_700_000_000_123, -3)));
+    assert_eq!(DynTimestamp::parse("1700000000123", -6), Ok(DynTimestamp::new(1_700_000_000_123, -6)));
+    assert_eq!(
+        DynTimestamp::parse("1700000000123", -3)
+            .map(|timestamp| timestamp.to_string())
+            .as_deref(),
+        Ok("1700000000.123")
+    );
+    assert_eq!(
+        DynTimestamp::parse("1700000000123", -6)
+            .map(|timestamp| timestamp.to_string())
+            .as_deref(),
+        Ok("1700000.000123")
+    );
+    assert!(DynTimestamp::parse("-1", 0).is_err());
+}
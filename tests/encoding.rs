@@ -0,0 +1,85 @@
+use timestamp_please::{FromStrRadixError, Timestamp, TimestampMilliseconds, TimestampSeconds};
+
+fn round_trip<const POWER: i32>(timestamp: Timestamp<u64, POWER>) -> Option<(Timestamp<u64, POWER>, usize)> {
+    let mut buf = [0u8; 10];
+    let len = timestamp.encode_varint(&mut buf)?;
+    Timestamp::<u64, POWER>::decode_varint(&buf[..len])
+}
+
+#[test]
+fn varint_encodes_small_values() {
+    let mut buf = [0u8; 10];
+    assert_eq!(TimestampSeconds::new(0).encode_varint(&mut buf), Some(1));
+    assert_eq!(buf[0], 0);
+    assert_eq!(TimestampSeconds::new(300).encode_varint(&mut buf), Some(2));
+    assert_eq!(buf[..2], [0xac, 0x02]);
+    assert_eq!(TimestampSeconds::decode_varint(&[0xac, 0x02]), Some((TimestampSeconds::new(300), 2)));
+}
+
+#[test]
+fn varint_round_trips_small_and_large_values() {
+    [
+        0,
+        1,
+        127,
+        128,
+        300,
+        1_700_000_000,
+        u64::from(u32::MAX) + 1,
+        u64::MAX,
+    ]
+    .into_iter()
+    .map(TimestampSeconds::new)
+    .for_each(|timestamp| assert_eq!(round_trip(timestamp).map(|(decoded, _)| decoded), Some(timestamp)));
+    assert_eq!(round_trip(TimestampSeconds::new(u64::MAX)).map(|(_, len)| len), Some(10));
+    let mut buf = [0u8; 19];
+    let timestamp = TimestampMilliseconds::new(u128::MAX);
+    assert_eq!(timestamp.encode_varint(&mut buf), Some(19));
+    assert_eq!(TimestampMilliseconds::decode_varint(&buf), Some((timestamp, 19)));
+}
+
+#[test]
+fn varint_decoding_ignores_trailing_bytes() {
+    assert_eq!(TimestampSeconds::decode_varint(&[0x01, 0xff, 0xff]), Some((TimestampSeconds::new(1), 1)));
+}
+
+#[test]
+fn varint_encoding_rejects_a_short_buffer() {
+    let mut buf = [0u8; 1];
+    assert_eq!(TimestampSeconds::new(300).encode_varint(&mut buf), None);
+    assert_eq!(TimestampSeconds::new(0).encode_varint(&mut []), None);
+}
+
+#[test]
+fn varint_decoding_rejects_truncated_input() {
+    assert_eq!(TimestampSeconds::decode_varint(&[]), None);
+    assert_eq!(TimestampSeconds::decode_varint(&[0x80]), None);
+    assert_eq!(TimestampSeconds::decode_varint(&[0xac]), None);
+}
+
+#[test]
+fn varint_decoding_rejects_u32_overflow() {
+    // `2^32` needs 33 bits
+    assert_eq!(Timestamp::<u32, 0>::decode_varint(&[0x80, 0x80, 0x80, 0x80, 0x10]), None);
+    // too many continuation bytes for `u32`
+    assert_eq!(Timestamp::<u32, 0>::decode_varint(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x00]), None);
+    assert_eq!(Timestamp::<u32, 0>::decode_varint(&[0xff, 0xff, 0xff, 0xff, 0x0f]), Some((Timestamp::new(u32::MAX), 5)));
+}
+
+#[test]
+fn from_str_radix_parses_hex_and_binary() {
+    assert_eq!(TimestampSeconds::from_str_radix("6553f100", 16), Ok(TimestampSeconds::new(1_700_000_000)));
+    assert_eq!(TimestampMilliseconds::from_str_radix("FF", 16), Ok(TimestampMilliseconds::new(255)));
+    assert_eq!(TimestampSeconds::from_str_radix("101", 2), Ok(TimestampSeconds::new(5)));
+}
+
+#[test]
+fn from_str_radix_rejects_invalid_input() {
+    assert_eq!(
+        TimestampSeconds::from_str_radix("10", 36),
+        Err(FromStrRadixError::InvalidRadix {
+            radix: 36
+        })
+    );
+    assert!(matches!(TimestampSeconds::from_str_radix("102", 2), Err(FromStrRadixError::InvalidValue { .. })));
+}
@@ -0,0 +1,99 @@
+use timestamp_please::{Timestamp, TimestampMilliseconds, TimestampNanoseconds, TimestampSeconds, UnrepresentableExcelSerialError, UnrepresentableJsMillisError, pow10_f64};
+
+#[test]
+fn unit_named_f64_accessors_convert_milliseconds() {
+    let timestamp = TimestampMilliseconds::new(1_500);
+    assert_eq!(timestamp.as_secs_f64(), 1.5);
+    assert_eq!(timestamp.as_millis_f64(), 1_500.0);
+    assert_eq!(timestamp.as_micros_f64(), 1_500_000.0);
+    assert_eq!(timestamp.as_nanos_f64(), 1_500_000_000.0);
+}
+
+#[test]
+fn unit_named_f64_accessors_handle_extreme_powers() {
+    // the exponent is computed in `i64`, so it doesn't overflow near `i32::MIN`
+    assert_eq!(Timestamp::<u64, { i32::MIN }>::new(1).as_nanos_f64(), 0.0);
+    assert_eq!(Timestamp::<u64, { i32::MIN }>::new(1).as_millis_f64(), 0.0);
+    assert_eq!(Timestamp::<u64, { i32::MAX }>::new(1).as_secs_f64(), f64::INFINITY);
+    assert_eq!(Timestamp::<u64, { i32::MAX }>::new(0).as_secs_f64(), 0.0);
+}
+
+#[test]
+fn pow10_f64_matches_the_exact_powers() {
+    (0..=22u8).for_each(|exp| assert_eq!(pow10_f64(u64::from(exp)), 10f64.powi(i32::from(exp)), "10^{exp}"));
+    assert!(pow10_f64(308).is_finite());
+    assert_eq!(pow10_f64(309), f64::INFINITY);
+    assert_eq!(pow10_f64(u64::MAX), f64::INFINITY);
+}
+
+#[test]
+fn scale_factor_matches_the_power() {
+    assert_eq!(Timestamp::<u64, -9>::scale_factor_f64(), 1e-9);
+    assert_eq!(Timestamp::<u64, -3>::scale_factor_f64(), 1e-3);
+    assert_eq!(Timestamp::<u64, 0>::scale_factor_f64(), 1.0);
+    assert_eq!(Timestamp::<u64, 3>::scale_factor_f64(), 1e3);
+}
+
+#[test]
+fn seconds_per_unit_fraction_matches_the_power() {
+    assert_eq!(Timestamp::<u64, -9>::seconds_per_unit_numerator_denominator(), Some((1, 1_000_000_000)));
+    assert_eq!(Timestamp::<u64, -3>::seconds_per_unit_numerator_denominator(), Some((1, 1_000)));
+    assert_eq!(Timestamp::<u64, 0>::seconds_per_unit_numerator_denominator(), Some((1, 1)));
+    assert_eq!(Timestamp::<u64, 3>::seconds_per_unit_numerator_denominator(), Some((1_000, 1)));
+    assert_eq!(Timestamp::<u64, -39>::seconds_per_unit_numerator_denominator(), None);
+}
+
+#[test]
+fn js_millis_round_trip_a_current_timestamp() {
+    let timestamp = TimestampMilliseconds::new(1_700_000_000_123);
+    assert_eq!(timestamp.to_js_millis(), 1_700_000_000_123.0);
+    assert_eq!(TimestampMilliseconds::from_js_millis(timestamp.to_js_millis()), Ok(timestamp));
+    let timestamp = TimestampNanoseconds::new(1_700_000_000_123_000_000);
+    assert_eq!(TimestampNanoseconds::from_js_millis(timestamp.to_js_millis()), Ok(timestamp));
+}
+
+#[test]
+fn from_js_millis_rejects_unrepresentable_values() {
+    assert_eq!(TimestampSeconds::from_js_millis(1_700_000_000_123.0), Err(UnrepresentableJsMillisError));
+    assert_eq!(TimestampMilliseconds::from_js_millis(1.5), Err(UnrepresentableJsMillisError));
+    assert_eq!(TimestampMilliseconds::from_js_millis(-1.0), Err(UnrepresentableJsMillisError));
+    assert_eq!(TimestampMilliseconds::from_js_millis(f64::NAN), Err(UnrepresentableJsMillisError));
+    assert_eq!(Timestamp::<i64, -3>::from_js_millis(-1.0), Ok(Timestamp::new(-1)));
+}
+
+#[test]
+fn from_excel_serial_converts_a_known_date() {
+    // 2023-11-14T12:00:00Z
+    assert_eq!(TimestampSeconds::from_excel_serial(45_244.5), Ok(TimestampSeconds::new(1_699_963_200)));
+    assert_eq!(TimestampMilliseconds::from_excel_serial(45_244.5), Ok(TimestampMilliseconds::new(1_699_963_200_000)));
+    // 1970-01-01 is serial 25569
+    assert_eq!(TimestampSeconds::from_excel_serial(25_569.0), Ok(TimestampSeconds::new(0)));
+    assert_eq!(Timestamp::<i64, 0>::from_excel_serial(25_568.0), Ok(Timestamp::new(-86_400)));
+}
+
+#[test]
+fn from_excel_serial_rejects_unrepresentable_serials() {
+    assert_eq!(TimestampSeconds::from_excel_serial(25_568.0), Err(UnrepresentableExcelSerialError));
+    assert_eq!(TimestampSeconds::from_excel_serial(f64::NAN), Err(UnrepresentableExcelSerialError));
+    assert_eq!(TimestampSeconds::from_excel_serial(f64::INFINITY), Err(UnrepresentableExcelSerialError));
+}
+
+#[test]
+fn to_excel_serial_converts_a_known_date() {
+    assert_eq!(TimestampSeconds::new(1_699_963_200).to_excel_serial(), 45_244.5);
+    assert_eq!(TimestampSeconds::new(0).to_excel_serial(), 25_569.0);
+}
+
+#[test]
+fn excel_serial_round_trips_within_float_tolerance() {
+    let millis = [0, 1_700_000_000_123, 1_999_999_999_999].map(TimestampMilliseconds::new);
+    millis.iter().for_each(|timestamp| {
+        let round_trip = TimestampMilliseconds::from_excel_serial(timestamp.to_excel_serial());
+        // rounding to the nearest millisecond absorbs the float error
+        assert_eq!(round_trip.map(|round_trip| round_trip.into_value().abs_diff(timestamp.into_value()) <= 1), Ok(true), "{timestamp:?}");
+    });
+    let nanos = TimestampNanoseconds::new(1_700_000_000_123_456_789);
+    let round_trip = TimestampNanoseconds::from_excel_serial(nanos.to_excel_serial());
+    // one ulp of a current serial is about 0.63 microseconds
+    assert_eq!(round_trip.map(|round_trip| round_trip.into_value().abs_diff(nanos.into_value()) < 10_000), Ok(true));
+}
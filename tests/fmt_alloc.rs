@@ -0,0 +1,15 @@
+//! The allocating formatters need only `alloc` (e.g. `cargo test --no-default-features --features alloc`)
+#![cfg(feature = "alloc")]
+
+use timestamp_please::{TimestampMilliseconds, TimestampSeconds};
+
+#[test]
+fn to_hex_formats_the_raw_value() {
+    assert_eq!(TimestampMilliseconds::new(1_700_000_000_000).to_hex(), "18bcfe56800");
+    assert_eq!(TimestampSeconds::new(0).to_hex(), "0");
+}
+
+#[test]
+fn humanize_relative_is_available_without_std() {
+    assert_eq!(TimestampSeconds::new(1_700_000_000).humanize_relative(TimestampSeconds::new(1_700_000_180)), "3 minutes ago");
+}
@@ -0,0 +1,236 @@
+use std::error::Error;
+use timestamp_please::{DynTimestamp, MAX_FIXED_POINT_ZEROS, Timestamp, TimestampMicroseconds, TimestampMilliseconds, TimestampNanoseconds, TimestampSeconds};
+
+#[test]
+fn display_with_unit_appends_the_standard_suffixes() {
+    assert_eq!(
+        TimestampSeconds::new(1_700_000_000)
+            .display_with_unit()
+            .to_string(),
+        "1700000000s"
+    );
+    assert_eq!(
+        TimestampMilliseconds::new(1_700_000_000_000)
+            .display_with_unit()
+            .to_string(),
+        "1700000000000ms"
+    );
+    assert_eq!(
+        TimestampMicroseconds::new(1_700_000_000_000_000)
+            .display_with_unit()
+            .to_string(),
+        "1700000000000000µs"
+    );
+    assert_eq!(
+        TimestampNanoseconds::new(1_700_000_000_000_000_000)
+            .display_with_unit()
+            .to_string(),
+        "1700000000000000000ns"
+    );
+}
+
+#[test]
+fn display_with_unit_falls_back_to_the_exponent() {
+    assert_eq!(
+        Timestamp::<u64, -2>::new(15)
+            .display_with_unit()
+            .to_string(),
+        "15e-2"
+    );
+    assert_eq!(Timestamp::<u64, 3>::new(15).display_with_unit().to_string(), "15e3");
+}
+
+#[test]
+fn integer_formatting_traits_pass_through_to_the_value() {
+    let timestamp = TimestampSeconds::new(255);
+    assert_eq!(format!("{timestamp:x}"), "ff");
+    assert_eq!(format!("{timestamp:#X}"), "0xFF");
+    assert_eq!(format!("{timestamp:b}"), "11111111");
+    assert_eq!(format!("{timestamp:#010b}"), "0b11111111");
+    assert_eq!(format!("{timestamp:o}"), "377");
+}
+
+#[test]
+fn positive_powers_render_a_whole_number_of_seconds() {
+    assert_eq!(Timestamp::<u64, 3>::new(5).display_as_seconds().to_string(), "5000");
+    assert_eq!(Timestamp::<u64, 3>::new(0).display_as_seconds().to_string(), "0");
+    assert_eq!(
+        Timestamp::<i64, 2>::new(-5)
+            .display_as_seconds()
+            .to_string(),
+        "-500"
+    );
+}
+
+#[test]
+fn large_positive_powers_render_every_zero() {
+    let rendered = DynTimestamp::new(5u64, 10_000).to_string();
+    assert_eq!(rendered.len(), 10_001);
+    assert!(rendered.starts_with('5'));
+    assert!(rendered[1..].bytes().all(|byte| byte == b'0'));
+}
+
+#[test]
+fn fixed_point_notation_rejects_more_zeros_than_the_limit() -> Result<(), Box<dyn Error>> {
+    use core::fmt::Write;
+    let limit = i32::try_from(MAX_FIXED_POINT_ZEROS)?;
+    let mut rendered = String::new();
+    assert!(write!(rendered, "{}", DynTimestamp::new(5u64, limit)).is_ok());
+    assert_eq!(rendered.len(), MAX_FIXED_POINT_ZEROS + 1);
+    assert!(write!(String::new(), "{}", DynTimestamp::new(5u64, limit + 1)).is_err());
+    assert!(write!(String::new(), "{}", DynTimestamp::new(5u64, i32::MAX)).is_err());
+    // the fractional padding is capped too
+    assert!(write!(String::new(), "{}", DynTimestamp::new(5u64, i32::MIN)).is_err());
+    // zero is never padded
+    assert_eq!(DynTimestamp::new(0u64, i32::MAX).to_string(), "0");
+    Ok(())
+}
+
+#[test]
+fn display_with_separator_uses_the_given_separator() {
+    let timestamp = TimestampMilliseconds::new(1_700_000_000_123);
+    assert_eq!(timestamp.display_with_separator(',').to_string(), "1700000000,123");
+    assert_eq!(timestamp.display_with_separator('.').to_string(), "1700000000.123");
+    assert_eq!(
+        TimestampMilliseconds::new(5)
+            .display_with_separator(',')
+            .to_string(),
+        "0,005"
+    );
+    assert_eq!(
+        TimestampSeconds::new(5)
+            .display_with_separator(',')
+            .to_string(),
+        "5"
+    );
+}
+
+#[test]
+fn display_as_seconds_renders_fixed_point_seconds() {
+    assert_eq!(
+        TimestampMilliseconds::new(1_700_000_000_123)
+            .display_as_seconds()
+            .to_string(),
+        "1700000000.123"
+    );
+    assert_eq!(
+        TimestampNanoseconds::new(5)
+            .display_as_seconds()
+            .to_string(),
+        "0.000000005"
+    );
+}
+
+/// An integer wrapper that implements only `Display` (not `itoa::Integer`)
+#[derive(Clone, Copy)]
+struct DisplayOnly(i64);
+
+impl core::fmt::Display for DisplayOnly {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[test]
+fn format_as_seconds_generic_formats_a_display_only_value() {
+    let render = |value, power| {
+        let mut output = String::new();
+        let result = match power {
+            -3 => Timestamp::<DisplayOnly, -3>::new(value).format_as_seconds_generic(&mut output),
+            0 => Timestamp::<DisplayOnly, 0>::new(value).format_as_seconds_generic(&mut output),
+            _ => Timestamp::<DisplayOnly, 2>::new(value).format_as_seconds_generic(&mut output),
+        };
+        result.map(|_| output)
+    };
+    assert_eq!(render(DisplayOnly(1_500), -3).as_deref(), Ok("1.500"));
+    assert_eq!(render(DisplayOnly(-5), -3).as_deref(), Ok("-0.005"));
+    assert_eq!(render(DisplayOnly(42), 0).as_deref(), Ok("42"));
+    assert_eq!(render(DisplayOnly(7), 2).as_deref(), Ok("700"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn humanize_relative_describes_past_and_future_offsets() {
+    let now = TimestampSeconds::new(1_700_000_000);
+    let humanize = |offset: i64| TimestampSeconds::new(1_700_000_000u64.saturating_add_signed(offset)).humanize_relative(now);
+    assert_eq!(humanize(0), "just now");
+    assert_eq!(humanize(1), "in 1 second");
+    assert_eq!(humanize(-45), "45 seconds ago");
+    assert_eq!(humanize(-180), "3 minutes ago");
+    assert_eq!(humanize(7_199), "in 1 hour");
+    assert_eq!(humanize(7_200), "in 2 hours");
+    assert_eq!(humanize(-3 * 86_400), "3 days ago");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn humanize_relative_treats_sub_second_gaps_as_just_now() {
+    let now = TimestampMilliseconds::new(1_700_000_000_000);
+    assert_eq!(TimestampMilliseconds::new(1_700_000_000_999).humanize_relative(now), "just now");
+    assert_eq!(TimestampMilliseconds::new(1_699_999_999_000).humanize_relative(now), "1 second ago");
+}
+
+#[test]
+fn display_with_max_fraction_truncates_nanoseconds_to_milliseconds() {
+    let timestamp = TimestampNanoseconds::new(1_700_000_000_123_999_999);
+    assert_eq!(timestamp.display_with_max_fraction(3).to_string(), "1700000000.123");
+    assert_eq!(timestamp.display_with_max_fraction(0).to_string(), "1700000000");
+    assert_eq!(timestamp.display_with_max_fraction(12).to_string(), "1700000000.123999999");
+    assert_eq!(
+        TimestampNanoseconds::new(5)
+            .display_with_max_fraction(3)
+            .to_string(),
+        "0.000"
+    );
+}
+
+#[test]
+fn display_scientific_normalized_shifts_the_decimal_point() {
+    assert_eq!(
+        TimestampSeconds::new(1_700_000_000)
+            .display_scientific_normalized()
+            .to_string(),
+        "1.7e9"
+    );
+    assert_eq!(
+        TimestampMilliseconds::new(1_700_000_000_123)
+            .display_scientific_normalized()
+            .to_string(),
+        "1.700000000123e9"
+    );
+    assert_eq!(
+        TimestampMilliseconds::new(5)
+            .display_scientific_normalized()
+            .to_string(),
+        "5e-3"
+    );
+    assert_eq!(
+        Timestamp::<u64, 3>::new(12)
+            .display_scientific_normalized()
+            .to_string(),
+        "1.2e4"
+    );
+}
+
+#[test]
+fn display_scientific_normalized_renders_zero() {
+    assert_eq!(
+        TimestampSeconds::new(0)
+            .display_scientific_normalized()
+            .to_string(),
+        "0"
+    );
+    assert_eq!(
+        TimestampNanoseconds::new(0)
+            .display_scientific_normalized()
+            .to_string(),
+        "0"
+    );
+}
+
+#[test]
+fn debug_is_compact_and_the_alternate_form_shows_the_power() {
+    let timestamp = TimestampMilliseconds::new(1_500);
+    assert_eq!(format!("{timestamp:?}"), "Timestamp { value: 1500 }");
+    assert_eq!(format!("{timestamp:#?}"), "Timestamp {\n    value: 1500,\n    power: -3,\n    rendered: \"1.500\",\n}");
+}
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use timestamp_please::{Timestamp, TimestampMilliseconds};
+
+fn hash_of(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn timestamps_work_as_hash_map_keys() {
+    let mut events = HashMap::new();
+    events.insert(TimestampMilliseconds::new(1_700_000_000_123), "start");
+    events.insert(TimestampMilliseconds::new(1_700_000_000_456), "stop");
+    events.insert(TimestampMilliseconds::new(1_700_000_000_123), "restart");
+    assert_eq!(events.len(), 2);
+    assert_eq!(events.get(&TimestampMilliseconds::new(1_700_000_000_123)), Some(&"restart"));
+    assert_eq!(events.get(&TimestampMilliseconds::new(1_700_000_000_456)), Some(&"stop"));
+    assert_eq!(events.get(&TimestampMilliseconds::new(1_700_000_000_000)), None);
+}
+
+#[test]
+fn hash_ignores_the_power() {
+    assert_eq!(hash_of(&TimestampMilliseconds::new(1_500)), hash_of(&1_500u128));
+    assert_eq!(hash_of(&Timestamp::<u128, 0>::new(1_500)), hash_of(&TimestampMilliseconds::new(1_500)));
+}
+
+#[test]
+fn hash_map_keys_with_different_powers_must_be_normalized() {
+    let events = HashMap::from([(TimestampMilliseconds::new(1_500), "start")]);
+    let key = Timestamp::<u128, -6>::new(1_500_000).try_scale::<-3>();
+    assert_eq!(key.ok().and_then(|key| events.get(&key)), Some(&"start"));
+}
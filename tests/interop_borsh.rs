@@ -0,0 +1,22 @@
+#![cfg(feature = "borsh")]
+
+use std::error::Error;
+use timestamp_please::{DynTimestamp, TimestampMilliseconds, TimestampSeconds};
+
+#[test]
+fn borsh_round_trips_the_raw_value() -> Result<(), Box<dyn Error>> {
+    let timestamp = TimestampMilliseconds::new(1_700_000_000_123);
+    let bytes = borsh::to_vec(&timestamp)?;
+    assert_eq!(bytes, 1_700_000_000_123u128.to_le_bytes());
+    assert_eq!(borsh::from_slice::<TimestampMilliseconds>(&bytes)?, timestamp);
+    let timestamp = TimestampSeconds::new(1_700_000_000);
+    assert_eq!(borsh::from_slice::<TimestampSeconds>(&borsh::to_vec(&timestamp)?)?, timestamp);
+    Ok(())
+}
+
+#[test]
+fn borsh_round_trips_dyn_timestamp() -> Result<(), Box<dyn Error>> {
+    let timestamp = DynTimestamp::new(1_500u64, -3);
+    assert_eq!(borsh::from_slice::<DynTimestamp>(&borsh::to_vec(&timestamp)?)?, timestamp);
+    Ok(())
+}
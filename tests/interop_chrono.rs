@@ -0,0 +1,135 @@
+#![cfg(feature = "chrono")]
+
+use chrono::{DateTime, Utc};
+use std::error::Error;
+use timestamp_please::{CalendarBoundaryError, Timestamp, TimestampMilliseconds, TimestampSeconds};
+
+#[test]
+fn clamped_for_chrono_clamps_a_huge_timestamp() -> Result<(), Box<dyn Error>> {
+    let huge = TimestampSeconds::new(u64::MAX);
+    assert!(DateTime::<Utc>::try_from(huge).is_err());
+    let clamped = huge.clamped_for_chrono();
+    assert_eq!(clamped, TimestampSeconds::new(9_223_372_036));
+    assert_eq!(DateTime::<Utc>::try_from(clamped)?.timestamp(), 9_223_372_036);
+    Ok(())
+}
+
+#[test]
+fn clamped_for_chrono_clamps_a_huge_negative_timestamp() -> Result<(), Box<dyn Error>> {
+    let clamped = Timestamp::<i64, 0>::new(i64::MIN).clamped_for_chrono();
+    assert_eq!(clamped, Timestamp::new(-9_223_372_036));
+    assert_eq!(DateTime::<Utc>::try_from(clamped)?.timestamp(), -9_223_372_036);
+    Ok(())
+}
+
+#[test]
+fn clamped_for_chrono_converts_i128_storage_at_any_power() -> Result<(), Box<dyn Error>> {
+    let clamped = Timestamp::<i128, -3>::new(i128::MAX).clamped_for_chrono();
+    assert_eq!(clamped, Timestamp::new(9_223_372_036_854));
+    assert_eq!(DateTime::<Utc>::try_from(clamped)?.timestamp_millis(), 9_223_372_036_854);
+    let dt = DateTime::<Utc>::from_timestamp(-1_700_000_000, 0).ok_or("out of range")?;
+    assert_eq!(Timestamp::<i128, 0>::try_from(dt), Ok(Timestamp::new(-1_700_000_000)));
+    Ok(())
+}
+
+#[test]
+fn clamped_for_chrono_keeps_a_representable_timestamp() {
+    assert_eq!(TimestampSeconds::new(1_700_000_000).clamped_for_chrono(), TimestampSeconds::new(1_700_000_000));
+    // sub-nanosecond digits are truncated
+    assert_eq!(Timestamp::<u64, -12>::new(1_500).clamped_for_chrono(), Timestamp::new(1_000));
+}
+
+#[test]
+fn start_of_year_matches_the_unix_value() {
+    assert_eq!(TimestampSeconds::start_of_year(2024), Ok(TimestampSeconds::new(1_704_067_200)));
+    assert_eq!(TimestampMilliseconds::start_of_year(2024), Ok(TimestampMilliseconds::new(1_704_067_200_000)));
+    assert_eq!(Timestamp::<i64, 0>::start_of_year(1969), Ok(Timestamp::new(-31_536_000)));
+}
+
+#[test]
+fn start_of_day_matches_the_unix_value() {
+    assert_eq!(TimestampSeconds::start_of_day(2024, 2, 29), Ok(TimestampSeconds::new(1_709_164_800)));
+}
+
+#[test]
+fn start_of_day_rejects_invalid_and_unrepresentable_dates() {
+    assert_eq!(TimestampSeconds::start_of_day(2023, 2, 29), Err(CalendarBoundaryError::InvalidDate));
+    assert!(matches!(TimestampSeconds::start_of_year(1969), Err(CalendarBoundaryError::Unrepresentable { .. })));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_rfc3339_matches_the_precision_of_the_power() {
+    use timestamp_please::{TimestampMicroseconds, TimestampNanoseconds};
+    assert_eq!(TimestampSeconds::new(1_700_000_000).to_rfc3339().as_deref(), Ok("2023-11-14T22:13:20Z"));
+    assert_eq!(
+        TimestampMilliseconds::new(1_700_000_000_123)
+            .to_rfc3339()
+            .as_deref(),
+        Ok("2023-11-14T22:13:20.123Z")
+    );
+    assert_eq!(
+        TimestampMicroseconds::new(1_700_000_000_123_456)
+            .to_rfc3339()
+            .as_deref(),
+        Ok("2023-11-14T22:13:20.123456Z")
+    );
+    assert_eq!(
+        TimestampNanoseconds::new(1_700_000_000_123_456_789)
+            .to_rfc3339()
+            .as_deref(),
+        Ok("2023-11-14T22:13:20.123456789Z")
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_iso8601_basic_formats_a_known_instant() {
+    assert_eq!(
+        TimestampSeconds::new(1_700_000_000)
+            .to_iso8601_basic()
+            .as_deref(),
+        Ok("20231114T221320Z")
+    );
+    // the fraction is dropped
+    assert_eq!(
+        TimestampMilliseconds::new(1_700_000_000_999)
+            .to_iso8601_basic()
+            .as_deref(),
+        Ok("20231114T221320Z")
+    );
+}
+
+#[test]
+fn until_next_midnight_utc_from_mid_day() {
+    use core::time::Duration;
+    // 2023-11-14T12:13:20Z
+    assert_eq!(TimestampSeconds::new(1_699_964_000).until_next_midnight_utc(), Ok(Duration::from_secs(42_400)));
+    assert_eq!(TimestampMilliseconds::new(1_699_964_000_250).until_next_midnight_utc(), Ok(Duration::from_millis(42_399_750)));
+    // exactly at midnight
+    assert_eq!(TimestampSeconds::new(1_699_920_000).until_next_midnight_utc(), Ok(Duration::from_secs(86_400)));
+}
+
+#[test]
+fn from_rfc2822_parses_a_valid_date() {
+    assert_eq!(TimestampSeconds::from_rfc2822("Tue, 14 Nov 2023 22:13:20 +0000"), Ok(TimestampSeconds::new(1_700_000_000)));
+    // the offset is applied
+    assert_eq!(TimestampMilliseconds::from_rfc2822("Wed, 15 Nov 2023 03:43:20 +0530"), Ok(TimestampMilliseconds::new(1_700_000_000_000)));
+}
+
+#[test]
+fn from_rfc2822_rejects_invalid_and_unrepresentable_dates() {
+    use timestamp_please::FromRfc2822Error;
+    assert!(matches!(TimestampSeconds::from_rfc2822("2023-11-14T22:13:20Z"), Err(FromRfc2822Error::InvalidRfc2822 { .. })));
+    assert!(matches!(TimestampSeconds::from_rfc2822("Wed, 31 Dec 1969 23:59:59 +0000"), Err(FromRfc2822Error::Unrepresentable { .. })));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_rfc2822_round_trips_through_from_rfc2822() -> Result<(), Box<dyn Error>> {
+    let timestamp = TimestampSeconds::new(1_700_000_000);
+    let formatted = timestamp.to_rfc2822()?;
+    assert_eq!(formatted, "Tue, 14 Nov 2023 22:13:20 +0000");
+    assert_eq!(TimestampSeconds::from_rfc2822(&formatted), Ok(timestamp));
+    Ok(())
+}
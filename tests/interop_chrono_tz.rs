@@ -0,0 +1,23 @@
+#![cfg(feature = "chrono-tz")]
+
+use chrono_tz::America::New_York;
+use timestamp_please::{FormatInTzError, Timestamp, TimestampSeconds};
+
+#[test]
+fn format_in_tz_renders_new_york_time() {
+    let timestamp = TimestampSeconds::new(1_700_000_000);
+    assert_eq!(timestamp.format_in_tz(New_York, "%Y-%m-%d %H:%M:%S %Z"), Ok("2023-11-14 17:13:20 EST".into()));
+    // daylight saving time
+    let timestamp = TimestampSeconds::new(1_690_000_000);
+    assert_eq!(timestamp.format_in_tz(New_York, "%Y-%m-%dT%H:%M:%S%:z"), Ok("2023-07-22T00:26:40-04:00".into()));
+}
+
+#[test]
+fn format_in_tz_rejects_invalid_format() {
+    assert_eq!(TimestampSeconds::new(1_700_000_000).format_in_tz(New_York, "%Q"), Err(FormatInTzError::InvalidFormat));
+}
+
+#[test]
+fn format_in_tz_rejects_unrepresentable_timestamp() {
+    assert!(matches!(Timestamp::<u64, -12>::new(1).format_in_tz(New_York, "%Y"), Err(FormatInTzError::Unrepresentable { .. })));
+}
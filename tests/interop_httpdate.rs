@@ -0,0 +1,32 @@
+#![cfg(feature = "httpdate")]
+
+use timestamp_please::{FromHttpDateError, TimestampMilliseconds, TimestampSeconds};
+
+#[test]
+fn from_http_date_parses_a_valid_header() {
+    assert_eq!(TimestampSeconds::from_http_date("Tue, 15 Nov 1994 08:12:31 GMT").ok(), Some(TimestampSeconds::new(784_887_151)));
+    assert_eq!(TimestampMilliseconds::from_http_date("Tue, 15 Nov 1994 08:12:31 GMT").ok(), Some(TimestampMilliseconds::new(784_887_151_000)));
+}
+
+#[test]
+fn from_http_date_rejects_a_malformed_header() {
+    assert!(matches!(TimestampSeconds::from_http_date("Tue, 15 Nov 1994 08:12"), Err(FromHttpDateError::InvalidHttpDate { .. })));
+}
+
+#[test]
+fn to_http_date_round_trips() {
+    assert_eq!(
+        TimestampSeconds::new(784_887_151)
+            .to_http_date()
+            .ok()
+            .as_deref(),
+        Some("Tue, 15 Nov 1994 08:12:31 GMT")
+    );
+    assert_eq!(
+        TimestampMilliseconds::new(784_887_151_999)
+            .to_http_date()
+            .ok()
+            .as_deref(),
+        Some("Tue, 15 Nov 1994 08:12:31 GMT")
+    );
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "rand")]
+
+use core::time::Duration;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use timestamp_please::{TimestampMilliseconds, TimestampSeconds};
+
+#[test]
+fn with_jitter_is_reproducible_with_a_seeded_rng() {
+    let timestamp = TimestampMilliseconds::new(1_700_000_000_000);
+    let max_jitter = Duration::from_secs(30);
+    let jittered = |seed| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        [0; 8].map(|_| timestamp.with_jitter(max_jitter, &mut rng))
+    };
+    let first = jittered(42);
+    assert_eq!(first, jittered(42));
+    first.iter().for_each(|jittered| {
+        assert!((1_700_000_000_000..=1_700_000_030_000).contains(&jittered.into_value()), "{jittered:?}");
+    });
+    // the offsets of 8 draws from 30001 values are very unlikely to all be equal
+    assert!(first.iter().any(|jittered| *jittered != first[0]));
+}
+
+#[test]
+fn with_jitter_keeps_the_value_with_zero_jitter() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let timestamp = TimestampMilliseconds::new(1_700_000_000_000);
+    assert_eq!(timestamp.with_jitter(Duration::ZERO, &mut rng), timestamp);
+    // sub-unit jitter truncates to zero units
+    assert_eq!(TimestampSeconds::new(1_700_000_000).with_jitter(Duration::from_millis(999), &mut rng), TimestampSeconds::new(1_700_000_000));
+}
+
+#[test]
+fn with_jitter_saturates() {
+    let mut rng = StdRng::seed_from_u64(42);
+    (0..16).for_each(|_| assert_eq!(TimestampSeconds::new(u64::MAX).with_jitter(Duration::from_secs(1_000), &mut rng), TimestampSeconds::new(u64::MAX)));
+}
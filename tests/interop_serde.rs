@@ -0,0 +1,18 @@
+#![cfg(feature = "serde")]
+
+use std::error::Error;
+use timestamp_please::DynTimestamp;
+
+#[test]
+fn dyn_timestamp_deserializes_long_and_short_keys() -> Result<(), Box<dyn Error>> {
+    let expected = DynTimestamp::new(1_700_000_000_123u64, -3);
+    assert_eq!(serde_json::from_str::<DynTimestamp>(r#"{"value":1700000000123,"power":-3}"#)?, expected);
+    assert_eq!(serde_json::from_str::<DynTimestamp>(r#"{"ts":1700000000123,"p":-3}"#)?, expected);
+    Ok(())
+}
+
+#[test]
+fn dyn_timestamp_serializes_long_keys() -> Result<(), Box<dyn Error>> {
+    assert_eq!(serde_json::to_string(&DynTimestamp::new(5u64, -3))?, r#"{"value":5,"power":-3}"#);
+    Ok(())
+}
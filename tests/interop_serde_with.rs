@@ -0,0 +1,27 @@
+#![cfg(feature = "serde_with")]
+
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use std::error::Error;
+use timestamp_please::TimestampMilliseconds;
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+struct Event {
+    #[serde_as(as = "TimestampMilliseconds")]
+    created_at: u128,
+    #[serde_as(as = "Option<TimestampMilliseconds>")]
+    deleted_at: Option<u128>,
+}
+
+#[test]
+fn serde_as_serializes_the_raw_value() -> Result<(), Box<dyn Error>> {
+    let event = Event {
+        created_at: 1_700_000_000_123,
+        deleted_at: None,
+    };
+    let json = serde_json::to_string(&event)?;
+    assert_eq!(json, r#"{"created_at":1700000000123,"deleted_at":null}"#);
+    assert_eq!(serde_json::from_str::<Event>(&json)?, event);
+    Ok(())
+}
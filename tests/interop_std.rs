@@ -0,0 +1,60 @@
+#![cfg(feature = "std")]
+
+use std::time::{Duration, UNIX_EPOCH};
+use timestamp_please::{FromSystemTimeError, Timestamp, TimestampMicroseconds, TimestampMilliseconds, TimestampNanoseconds, TimestampSeconds};
+
+#[test]
+fn from_system_time_converts_post_epoch_times() {
+    let system_time = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+    assert_eq!(TimestampMilliseconds::from_system_time(system_time).ok(), Some(TimestampMilliseconds::new(1_700_000_000_123)));
+    assert_eq!(TimestampMicroseconds::from_system_time(system_time).ok(), Some(TimestampMicroseconds::new(1_700_000_000_123_000)));
+    assert_eq!(TimestampNanoseconds::from_system_time(system_time).ok(), Some(TimestampNanoseconds::new(1_700_000_000_123_000_000)));
+    // the sub-second digits are truncated
+    assert_eq!(TimestampSeconds::from_system_time(system_time).ok(), Some(TimestampSeconds::new(1_700_000_000)));
+}
+
+#[test]
+fn from_system_time_rejects_pre_epoch_times() {
+    let system_time = UNIX_EPOCH - Duration::from_secs(1);
+    assert!(TimestampSeconds::from_system_time(system_time).is_err());
+    assert!(TimestampMilliseconds::from_system_time(system_time).is_err());
+    assert!(TimestampNanoseconds::from_system_time(system_time).is_err());
+}
+
+#[test]
+fn try_from_system_time_truncates_sub_unit_digits() {
+    let system_time = UNIX_EPOCH + Duration::from_nanos(1_700_000_000_123_456_789);
+    assert_eq!(TimestampSeconds::try_from(system_time).ok(), Some(TimestampSeconds::new(1_700_000_000)));
+    assert_eq!(Timestamp::<u64, -3>::try_from(system_time).ok(), Some(Timestamp::new(1_700_000_000_123)));
+    assert_eq!(Timestamp::<u64, -9>::try_from(system_time).ok(), Some(Timestamp::new(1_700_000_000_123_456_789)));
+    assert_eq!(Timestamp::<u64, 3>::try_from(system_time).ok(), Some(Timestamp::new(1_700_000)));
+}
+
+#[test]
+fn try_from_system_time_rejects_pre_epoch_times_and_overflow() {
+    assert!(matches!(Timestamp::<u64, -3>::try_from(UNIX_EPOCH - Duration::from_secs(1)), Err(FromSystemTimeError::BeforeEpoch { .. })));
+    assert!(matches!(Timestamp::<u64, -12>::try_from(UNIX_EPOCH + Duration::from_secs(1_700_000_000)), Err(FromSystemTimeError::Overflow)));
+}
+
+#[test]
+fn from_system_time_exact_converts_whole_units() {
+    let system_time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    assert_eq!(Timestamp::<u32, 0>::from_system_time_exact(system_time).ok(), Some(Timestamp::new(1_700_000_000)));
+    assert_eq!(Timestamp::<u64, -12>::from_system_time_exact(UNIX_EPOCH + Duration::from_secs(1)).ok(), Some(Timestamp::new(1_000_000_000_000)));
+    assert_eq!(Timestamp::<u128, -9>::from_system_time_exact(system_time).ok(), Some(Timestamp::new(1_700_000_000_000_000_000)));
+}
+
+#[test]
+fn from_system_time_exact_rejects_sub_unit_digits() {
+    let system_time = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+    assert!(matches!(Timestamp::<u64, 0>::from_system_time_exact(system_time), Err(FromSystemTimeError::Inexact)));
+    assert!(matches!(Timestamp::<u128, -3>::from_system_time_exact(system_time + Duration::from_nanos(1)), Err(FromSystemTimeError::Inexact)));
+}
+
+#[test]
+fn from_system_time_exact_rejects_pre_epoch_times_and_overflow() {
+    assert!(matches!(Timestamp::<u64, 0>::from_system_time_exact(UNIX_EPOCH - Duration::from_secs(1)), Err(FromSystemTimeError::BeforeEpoch { .. })));
+    let system_time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    assert!(matches!(Timestamp::<u32, -3>::from_system_time_exact(system_time), Err(FromSystemTimeError::Overflow)));
+    assert!(matches!(Timestamp::<u64, -12>::from_system_time_exact(system_time), Err(FromSystemTimeError::Overflow)));
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "time")]
+
+use std::error::Error;
+use timestamp_please::{Timestamp, TimestampMilliseconds, TimestampSeconds, UnrepresentableOffsetDateTimeError, UnrepresentableTimeDurationError};
+
+#[test]
+fn time_duration_round_trips() -> Result<(), Box<dyn Error>> {
+    let timestamp = TimestampMilliseconds::new(1_700_000_000_123);
+    let duration = time::Duration::try_from(timestamp)?;
+    assert_eq!(duration, time::Duration::milliseconds(1_700_000_000_123));
+    assert_eq!(TimestampMilliseconds::try_from(duration), Ok(timestamp));
+    let timestamp = TimestampSeconds::new(1_700_000_000);
+    assert_eq!(TimestampSeconds::try_from(time::Duration::try_from(timestamp)?), Ok(timestamp));
+    Ok(())
+}
+
+#[test]
+fn time_duration_rejects_negative_and_inexact_durations() {
+    assert_eq!(TimestampSeconds::try_from(time::Duration::seconds(-1)), Err(UnrepresentableTimeDurationError));
+    assert_eq!(TimestampSeconds::try_from(time::Duration::milliseconds(1_500)), Err(UnrepresentableTimeDurationError));
+    assert_eq!(time::Duration::try_from(Timestamp::<u64, -12>::new(1)), Err(UnrepresentableTimeDurationError));
+}
+
+#[test]
+fn offset_date_time_around_epoch_into_unsigned_storage() -> Result<(), Box<dyn Error>> {
+    use time::OffsetDateTime;
+    let epoch = OffsetDateTime::UNIX_EPOCH;
+    let second = time::Duration::seconds(1);
+    assert_eq!(TimestampSeconds::try_from(epoch), Ok(TimestampSeconds::new(0)));
+    // unsigned storage clamps the pre-epoch datetimes to zero
+    assert_eq!(TimestampSeconds::try_from(epoch - second), Ok(TimestampSeconds::new(0)));
+    assert_eq!(TimestampSeconds::try_from(epoch + second), Ok(TimestampSeconds::new(1)));
+    assert_eq!(OffsetDateTime::try_from(TimestampSeconds::new(1))?, epoch + second);
+    Ok(())
+}
+
+#[test]
+fn offset_date_time_around_epoch_into_signed_storage() -> Result<(), Box<dyn Error>> {
+    use time::OffsetDateTime;
+    let epoch = OffsetDateTime::UNIX_EPOCH;
+    let second = time::Duration::seconds(1);
+    assert_eq!(Timestamp::<i64, 0>::try_from(epoch), Ok(Timestamp::new(0)));
+    assert_eq!(Timestamp::<i64, 0>::try_from(epoch - second), Ok(Timestamp::new(-1)));
+    assert_eq!(Timestamp::<i64, 0>::try_from(epoch + second), Ok(Timestamp::new(1)));
+    assert_eq!(OffsetDateTime::try_from(Timestamp::<i64, 0>::new(-1))?, epoch - second);
+    Ok(())
+}
+
+#[test]
+fn offset_date_time_floors_the_digits_below_the_unit() -> Result<(), Box<dyn Error>> {
+    use time::OffsetDateTime;
+    let dt = OffsetDateTime::from_unix_timestamp_nanos(1_700_000_000_123_456_789)?;
+    assert_eq!(TimestampSeconds::try_from(dt), Ok(TimestampSeconds::new(1_700_000_000)));
+    assert_eq!(TimestampMilliseconds::try_from(dt), Ok(TimestampMilliseconds::new(1_700_000_000_123)));
+    // a signed value is floored toward the past, not toward the epoch
+    let dt = OffsetDateTime::from_unix_timestamp_nanos(-1_500_000_000)?;
+    assert_eq!(Timestamp::<i64, 0>::try_from(dt), Ok(Timestamp::new(-2)));
+    assert_eq!(Timestamp::<i64, -3>::try_from(dt), Ok(Timestamp::new(-1_500)));
+    Ok(())
+}
+
+#[test]
+fn offset_date_time_rejects_overflowing_values() -> Result<(), Box<dyn Error>> {
+    use time::OffsetDateTime;
+    let dt = OffsetDateTime::from_unix_timestamp(1_700_000_000)?;
+    assert_eq!(Timestamp::<u32, -3>::try_from(dt), Err(UnrepresentableOffsetDateTimeError));
+    assert_eq!(Timestamp::<i64, -9>::try_from(OffsetDateTime::from_unix_timestamp(-10_000_000_000)?), Err(UnrepresentableOffsetDateTimeError));
+    // the reverse conversion stays exact
+    assert_eq!(OffsetDateTime::try_from(Timestamp::<u64, -12>::new(1_500)), Err(UnrepresentableOffsetDateTimeError));
+    Ok(())
+}
+
+#[test]
+fn offset_date_time_methods_convert_i128_storage_at_any_power() -> Result<(), Box<dyn Error>> {
+    use time::{OffsetDateTime, UtcOffset};
+    let dt = OffsetDateTime::from_unix_timestamp_nanos(-1_700_000_000_123_000_000)?;
+    assert_eq!(Timestamp::<i128, -3>::from_offset_datetime(dt), Ok(Timestamp::new(-1_700_000_000_123)));
+    assert_eq!(Timestamp::<i128, 0>::from_offset_datetime(dt), Ok(Timestamp::new(-1_700_000_001)));
+    assert_eq!(Timestamp::<i128, -3>::new(-1_700_000_000_123).to_offset_datetime(UtcOffset::UTC)?, dt);
+    Ok(())
+}
+
+#[test]
+fn to_offset_datetime_applies_the_offset() -> Result<(), Box<dyn Error>> {
+    let offset = time::UtcOffset::from_hms(5, 30, 0)?;
+    let dt = TimestampSeconds::new(1_700_000_000).to_offset_datetime(offset)?;
+    assert_eq!(dt.offset(), offset);
+    assert_eq!((dt.year(), u8::from(dt.month()), dt.day()), (2023, 11, 15));
+    assert_eq!((dt.hour(), dt.minute(), dt.second()), (3, 43, 20));
+    assert_eq!(dt.unix_timestamp(), 1_700_000_000);
+    Ok(())
+}
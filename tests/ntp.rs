@@ -0,0 +1,56 @@
+use timestamp_please::{Timestamp, TimestampMicroseconds, TimestampMilliseconds, TimestampNanoseconds, TimestampSeconds};
+
+/// 2023-11-14T22:13:20.5Z as NTP seconds since 1900 and a fraction of `2^-32` seconds
+const NTP_SECONDS: u32 = 3_908_988_800;
+const NTP_HALF: u32 = 0x8000_0000;
+
+#[test]
+fn from_ntp_converts_a_known_timestamp() {
+    assert_eq!(TimestampSeconds::from_ntp(NTP_SECONDS, NTP_HALF), Some(TimestampSeconds::new(1_700_000_000)));
+    assert_eq!(TimestampMilliseconds::from_ntp(NTP_SECONDS, NTP_HALF), Some(TimestampMilliseconds::new(1_700_000_000_500)));
+    assert_eq!(TimestampNanoseconds::from_ntp(NTP_SECONDS, 1), Some(TimestampNanoseconds::new(1_700_000_000_000_000_000)));
+    // 2208988800 seconds since 1900 is Unix epoch
+    assert_eq!(TimestampSeconds::from_ntp(2_208_988_800, 0), Some(TimestampSeconds::new(0)));
+}
+
+#[test]
+fn from_ntp_rejects_times_before_unix_epoch() {
+    assert_eq!(TimestampSeconds::from_ntp(2_208_988_799, u32::MAX), None);
+    assert_eq!(Timestamp::<u32, -3>::from_ntp(NTP_SECONDS, 0), None);
+}
+
+#[test]
+fn to_ntp_converts_a_known_timestamp() {
+    assert_eq!(TimestampMilliseconds::new(1_700_000_000_500).to_ntp(), Some((NTP_SECONDS, NTP_HALF)));
+    assert_eq!(TimestampSeconds::new(0).to_ntp(), Some((2_208_988_800, 0)));
+}
+
+#[test]
+fn to_ntp_returns_none_after_the_era_rollover() {
+    // 2036-02-07T06:28:16Z
+    assert_eq!(TimestampSeconds::new(2_085_978_495).to_ntp(), Some((u32::MAX, 0)));
+    assert_eq!(TimestampSeconds::new(2_085_978_496).to_ntp(), None);
+}
+
+#[test]
+fn to_ntp_round_trips_through_from_ntp() {
+    let millis = [0, 1, 999, 1_700_000_000_123, 2_085_978_495_999].map(TimestampMilliseconds::new);
+    millis.iter().for_each(|timestamp| {
+        let ntp = timestamp.to_ntp();
+        assert_eq!(ntp.and_then(|(seconds, fraction)| TimestampMilliseconds::from_ntp(seconds, fraction)), Some(*timestamp), "{ntp:?}");
+    });
+    let micros = TimestampMicroseconds::new(1_700_000_000_123_456);
+    assert_eq!(
+        micros
+            .to_ntp()
+            .and_then(|(seconds, fraction)| TimestampMicroseconds::from_ntp(seconds, fraction)),
+        Some(micros)
+    );
+    let nanos = TimestampNanoseconds::new(1_700_000_000_999_999_999);
+    assert_eq!(
+        nanos
+            .to_ntp()
+            .and_then(|(seconds, fraction)| TimestampNanoseconds::from_ntp(seconds, fraction)),
+        Some(nanos)
+    );
+}
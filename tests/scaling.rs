@@ -0,0 +1,237 @@
+use timestamp_please::{OverflowPolicy, RoundingMode, ScaleFailureReason, Timestamp, TimestampMicroseconds, TimestampMilliseconds, TimestampNanoseconds, TimestampTryScaleError, pow10_u64, pow10_u128, rescale_iter};
+
+const U64_SAMPLES: [u64; 9] = [
+    0,
+    1,
+    999,
+    1_000,
+    1_001,
+    1_700_000_000_123,
+    u64::MAX / 1_000,
+    u64::MAX / 1_000 + 1,
+    u64::MAX,
+];
+
+#[test]
+fn unit_named_scaling_methods_widen() {
+    let timestamp = TimestampMilliseconds::new(1_500);
+    assert_eq!(timestamp.to_millis(), Ok(Timestamp::new(1_500)));
+    assert_eq!(timestamp.to_micros(), Ok(Timestamp::new(1_500_000)));
+    assert_eq!(timestamp.to_nanos(), Ok(Timestamp::new(1_500_000_000)));
+    assert_eq!(Timestamp::<u128, 0>::new(2).to_millis(), Ok(Timestamp::new(2_000)));
+}
+
+#[test]
+fn unit_named_scaling_methods_truncate_when_narrowing() {
+    assert_eq!(TimestampMilliseconds::new(1_500).to_seconds(), Ok(Timestamp::new(1)));
+    assert_eq!(TimestampNanoseconds::new(1_234_567_899).to_millis(), Ok(Timestamp::new(1_234)));
+    assert_eq!(TimestampNanoseconds::new(1_234_567_899).to_micros(), Ok(Timestamp::new(1_234_567)));
+    assert_eq!(TimestampMicroseconds::new(999_999).to_seconds(), Ok(Timestamp::new(0)));
+}
+
+#[test]
+fn unit_named_scaling_methods_fail_on_overflow() {
+    assert!(TimestampMilliseconds::new(u128::MAX).to_nanos().is_err());
+}
+
+#[test]
+fn try_scale_signed_truncates_toward_zero() {
+    assert_eq!(Timestamp::<i64, -3>::new(-1_500).try_scale_signed::<0>(), Ok(Timestamp::new(-1)));
+    assert_eq!(Timestamp::<i64, -3>::new(1_500).try_scale_signed::<0>(), Ok(Timestamp::new(1)));
+}
+
+#[test]
+fn try_scale_signed_widens_negative_values() {
+    assert_eq!(Timestamp::<i64, -3>::new(-1_500).try_scale_signed::<-6>(), Ok(Timestamp::new(-1_500_000)));
+    assert_eq!(Timestamp::<i64, 0>::new(i64::MIN / 1_000).try_scale_signed::<-3>(), Ok(Timestamp::new(i64::MIN / 1_000 * 1_000)));
+}
+
+#[test]
+fn try_scale_signed_fails_on_overflow() {
+    assert!(matches!(Timestamp::<i64, 0>::new(i64::MIN).try_scale_signed::<-3>(), Err(TimestampTryScaleError::SignedScaleFailed { value, .. }) if value == i128::from(i64::MIN)));
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn lossless_scale_possible_detects_lossless_power_pairs() {
+    assert!(TimestampMilliseconds::lossless_scale_possible::<-9>());
+    assert!(TimestampMilliseconds::lossless_scale_possible::<-3>());
+    assert!(!TimestampMilliseconds::lossless_scale_possible::<0>());
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn debug_round_trip_recovers_lossless_scaling() {
+    assert!(TimestampMilliseconds::new(1_700_000_000_123).debug_round_trip::<-9>());
+    assert!(TimestampMilliseconds::new(1_700_000_000_000).debug_round_trip::<0>());
+    assert!(!TimestampMilliseconds::new(1_700_000_000_123).debug_round_trip::<0>());
+    // lossless, but overflows
+    assert!(!TimestampMilliseconds::new(u128::MAX).debug_round_trip::<-9>());
+}
+
+#[test]
+fn scale_to_runtime_power_rescales_milliseconds() {
+    let timestamp = TimestampMilliseconds::new(1_700_000_000_123);
+    assert_eq!(timestamp.scale_to_runtime_power(0), Some((1_700_000_000, 0)));
+    assert_eq!(timestamp.scale_to_runtime_power(-9), Some((1_700_000_000_123_000_000, -9)));
+    assert_eq!(TimestampMilliseconds::new(u128::MAX).scale_to_runtime_power(-9), None);
+}
+
+#[test]
+fn wrapping_scale_wraps_a_widening_that_overflows_u64() {
+    let timestamp = Timestamp::<u64, 0>::new(u64::MAX);
+    assert_eq!(timestamp.wrapping_scale::<-3>(), Timestamp::new(u64::MAX.wrapping_mul(1_000)));
+    assert_eq!(Timestamp::<u64, 0>::new(1 << 63).wrapping_scale::<-1>(), Timestamp::new(0));
+    assert_eq!(Timestamp::<u64, 0>::new(1).wrapping_scale::<-64>(), Timestamp::new(0));
+}
+
+#[test]
+fn wrapping_scale_is_exact_when_the_result_fits() {
+    assert_eq!(Timestamp::<u64, 0>::new(1_700_000_000).wrapping_scale::<-3>(), Timestamp::new(1_700_000_000_000));
+    assert_eq!(Timestamp::<u64, -3>::new(1_700_000_000_999).wrapping_scale::<0>(), Timestamp::new(1_700_000_000));
+}
+
+#[test]
+fn try_scale_into_value_matches_try_scale() {
+    U64_SAMPLES.iter().for_each(|&value| {
+        let timestamp = Timestamp::<u64, 0>::new(value);
+        assert_eq!(timestamp.try_scale_into_value::<-3>(), timestamp.try_scale::<-3>().map(Timestamp::into_value));
+        assert_eq!(timestamp.try_scale_into_value::<3>(), timestamp.try_scale::<3>().map(Timestamp::into_value));
+    });
+}
+
+#[test]
+fn try_scale_error_distinguishes_the_exponent_from_the_value_overflow() {
+    let exponent = Timestamp::<u128, 0>::new(1).try_scale::<-40>();
+    let value = Timestamp::<u128, 0>::new(u128::MAX).try_scale::<-3>();
+    assert!(matches!(
+        exponent,
+        Err(TimestampTryScaleError::ScaleFailed {
+            reason: ScaleFailureReason::ExponentOutOfRange,
+            ..
+        })
+    ));
+    assert!(matches!(
+        value,
+        Err(TimestampTryScaleError::ScaleFailed {
+            reason: ScaleFailureReason::ValueTooLarge,
+            ..
+        })
+    ));
+    assert_eq!(exponent.map_err(|error| error.to_string()), Err("failed to scale 1 from power 0 to power -40: exponent out of range".into()));
+    assert_eq!(value.map_err(|error| error.to_string()), Err(format!("failed to scale {} from power 0 to power -3: value too large after scaling", u128::MAX)));
+}
+
+#[test]
+fn rescale_iter_collects_a_rescaled_stream() {
+    let millis = [1_000, 1_500, 2_250].map(TimestampMilliseconds::new);
+    let micros = rescale_iter::<u128, -3, -6>(millis).collect::<Result<Vec<_>, _>>();
+    assert_eq!(
+        micros,
+        Ok([1_000_000, 1_500_000, 2_250_000]
+            .map(TimestampMicroseconds::new)
+            .to_vec())
+    );
+}
+
+#[test]
+fn rescale_iter_stops_collecting_at_the_first_error() {
+    let millis = [1_000, u128::MAX, 2_000].map(TimestampMilliseconds::new);
+    let micros = rescale_iter::<u128, -3, -6>(millis).collect::<Result<Vec<_>, _>>();
+    assert!(matches!(
+        micros,
+        Err(TimestampTryScaleError::ScaleFailed {
+            reason: ScaleFailureReason::ValueTooLarge,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn try_scale_with_rounding_rounds_halves_to_even() {
+    let round = |value| TimestampMilliseconds::new(value).try_scale_with_rounding::<0>(RoundingMode::NearestEven);
+    assert_eq!(round(2_500), Ok(Timestamp::new(2)));
+    assert_eq!(round(3_500), Ok(Timestamp::new(4)));
+    assert_eq!(round(2_501), Ok(Timestamp::new(3)));
+    assert_eq!(round(3_499), Ok(Timestamp::new(3)));
+}
+
+#[test]
+fn try_scale_with_rounding_compares_the_modes_at_a_half() {
+    let timestamp = TimestampMilliseconds::new(2_500);
+    assert_eq!(timestamp.try_scale_with_rounding::<0>(RoundingMode::Truncate), Ok(Timestamp::new(2)));
+    assert_eq!(timestamp.try_scale_with_rounding::<0>(RoundingMode::Nearest), Ok(Timestamp::new(3)));
+    assert_eq!(timestamp.try_scale_with_rounding::<0>(RoundingMode::NearestEven), Ok(Timestamp::new(2)));
+}
+
+#[test]
+fn scale_by_ratio_converts_ntsc_and_frame_counts() {
+    assert_eq!(TimestampMilliseconds::new(1_000_000).scale_by_ratio(1_001, 1_000), Some(TimestampMilliseconds::new(1_001_000)));
+    // truncates the result
+    assert_eq!(TimestampMilliseconds::new(999).scale_by_ratio(1_001, 1_000), Some(TimestampMilliseconds::new(999)));
+    assert_eq!(Timestamp::<u64, 0>::new(60).scale_by_ratio(30, 1), Some(Timestamp::new(1_800)));
+}
+
+#[test]
+fn scale_by_ratio_rejects_a_zero_denominator_and_overflow() {
+    assert_eq!(TimestampMilliseconds::new(1_000).scale_by_ratio(30, 0), None);
+    assert_eq!(TimestampMilliseconds::new(u128::MAX).scale_by_ratio(30, 1), None);
+    // the intermediate product would overflow, but the result fits
+    assert_eq!(TimestampMilliseconds::new(u128::MAX).scale_by_ratio(1_000, 1_001), Some(TimestampMilliseconds::new(u128::MAX / 1_001 * 1_000 + u128::MAX % 1_001 * 1_000 / 1_001)));
+}
+
+#[test]
+fn scale_by_ratio_handles_a_large_numerator_and_denominator() {
+    // `remainder * num` overflows `u128` here, so the product needs a wider intermediate
+    let huge = u128::MAX / 3;
+    assert_eq!(TimestampMilliseconds::new(u128::MAX - 1).scale_by_ratio(huge, huge), Some(TimestampMilliseconds::new(u128::MAX - 1)));
+    assert_eq!(TimestampMilliseconds::new(u128::MAX).scale_by_ratio(huge, huge + 1), Some(TimestampMilliseconds::new(u128::MAX - 3)));
+    assert_eq!(TimestampMilliseconds::new(1 << 100).scale_by_ratio(1 << 120, 1 << 110), Some(TimestampMilliseconds::new(1 << 110)));
+    assert_eq!(TimestampMilliseconds::new(u128::MAX).scale_by_ratio(huge + 1, huge), None);
+}
+
+#[test]
+fn pow10_tables_match_the_computed_powers() {
+    (0..=45).for_each(|exp| {
+        assert_eq!(pow10_u64(exp), 10u64.checked_pow(exp), "10^{exp}");
+        assert_eq!(pow10_u128(exp), 10u128.checked_pow(exp), "10^{exp}");
+    });
+    assert_eq!(pow10_u64(u32::MAX), None);
+    assert_eq!(pow10_u128(u32::MAX), None);
+}
+
+#[test]
+fn wrapping_scale_wraps_every_unsigned_storage() {
+    assert_eq!(Timestamp::<u32, 0>::new(u32::MAX).wrapping_scale::<-3>(), Timestamp::new(u32::MAX.wrapping_mul(1_000)));
+    assert_eq!(Timestamp::<u128, 0>::new(u128::MAX).wrapping_scale::<-3>(), Timestamp::new(u128::MAX.wrapping_mul(1_000)));
+    assert_eq!(Timestamp::<u128, 0>::new(1).wrapping_scale::<-128>(), Timestamp::new(0));
+    assert_eq!(Timestamp::<u32, -3>::new(u32::MAX).wrapping_scale::<0>(), Timestamp::new(u32::MAX / 1_000));
+    assert_eq!(Timestamp::<u128, -40>::new(u128::MAX).wrapping_scale::<0>(), Timestamp::new(0));
+}
+
+#[test]
+fn convert_with_policy_handles_an_overflowing_conversion() {
+    let timestamp = Timestamp::<u64, 0>::new(u64::MAX);
+    assert!(matches!(
+        timestamp.convert_with_policy::<-3>(OverflowPolicy::Error),
+        Err(TimestampTryScaleError::ScaleFailed {
+            reason: ScaleFailureReason::ValueTooLarge,
+            ..
+        })
+    ));
+    assert_eq!(timestamp.convert_with_policy::<-3>(OverflowPolicy::Saturate), Ok(Timestamp::new(u64::MAX)));
+    assert_eq!(timestamp.convert_with_policy::<-3>(OverflowPolicy::Wrap), Ok(timestamp.wrapping_scale::<-3>()));
+    assert_eq!(timestamp.convert_with_policy::<-3>(OverflowPolicy::Wrap), Ok(Timestamp::new(u64::MAX.wrapping_mul(1_000))));
+}
+
+#[test]
+fn convert_with_policy_agrees_on_a_fitting_conversion() {
+    let timestamp = Timestamp::<u32, -3>::new(1_500);
+    [
+        OverflowPolicy::Error,
+        OverflowPolicy::Saturate,
+        OverflowPolicy::Wrap,
+    ]
+    .into_iter()
+    .for_each(|policy| assert_eq!(timestamp.convert_with_policy::<0>(policy), Ok(Timestamp::new(1)), "{policy:?}"));
+}
@@ -0,0 +1,81 @@
+use timestamp_please::{Timestamp, TimestampMilliseconds, TimestampSeconds};
+
+#[test]
+fn try_into_value_as_narrows_a_fitting_value() {
+    assert_eq!(TimestampSeconds::new(1_700_000_000).try_into_value_as::<u32>(), Ok(1_700_000_000));
+}
+
+#[test]
+fn try_into_value_as_rejects_an_overflowing_value() {
+    assert!(
+        TimestampSeconds::new(u64::from(u32::MAX) + 1)
+            .try_into_value_as::<u32>()
+            .is_err()
+    );
+}
+
+#[test]
+fn timestamp_equals_its_raw_value() {
+    assert_eq!(TimestampSeconds::new(1_700), 1_700u64);
+    assert_ne!(TimestampSeconds::new(1_700), 1_701u64);
+    assert_eq!(TimestampMilliseconds::new(1_700), 1_700u128);
+    // the power is ignored
+    assert_eq!(Timestamp::<u128, 0>::new(1_700), 1_700u128);
+}
+
+#[test]
+fn try_from_narrows_u128_storage_into_u64() {
+    assert_eq!(Timestamp::<u64, -3>::try_from(TimestampMilliseconds::new(1_700_000_000_123)), Ok(Timestamp::new(1_700_000_000_123)));
+    assert!(Timestamp::<u64, -3>::try_from(TimestampMilliseconds::new(u128::from(u64::MAX) + 1)).is_err());
+}
+
+#[test]
+fn try_convert_storage_narrows_fallibly_and_widens_infallibly() {
+    assert_eq!(TimestampMilliseconds::new(1_700_000_000_123).try_convert_storage::<u64>(), Ok(Timestamp::<u64, -3>::new(1_700_000_000_123)));
+    assert!(
+        TimestampMilliseconds::new(u128::MAX)
+            .try_convert_storage::<u64>()
+            .is_err()
+    );
+    assert_eq!(TimestampSeconds::new(u64::MAX).try_convert_storage::<u128>(), Ok(Timestamp::<u128, 0>::new(u128::from(u64::MAX))));
+}
+
+macro_rules! assert_widens_losslessly {
+    ($($from:ty, $power_from:literal => $into:ty, $power_into:literal);+ $(;)?) => {
+        $(
+            let factor = <$into>::pow(10, ($power_from - $power_into) as u32);
+            for value in [0, 1, 1_000_000, <$from>::MIN, <$from>::MAX] {
+                let widened = Timestamp::<$into, $power_into>::from(Timestamp::<$from, $power_from>::new(value));
+                assert_eq!(widened, Timestamp::new(<$into>::from(value) * factor), "{value} from {} to {}", stringify!($from), stringify!($into));
+                // the widening is lossless
+                assert_eq!(<$from>::try_from(widened.into_value() / factor), Ok(value));
+            }
+        )+
+    };
+}
+
+#[test]
+fn from_widens_into_finer_units_losslessly() {
+    assert_widens_losslessly!(
+        u32, 0 => u64, -3;
+        u32, -3 => u64, -6;
+        u32, -6 => u64, -9;
+        u64, 0 => u128, -3;
+        u64, -3 => u128, -6;
+        u64, -6 => u128, -9;
+        u64, 0 => u128, -6;
+        u64, 0 => u128, -9;
+        i32, 0 => i64, -3;
+        i32, -3 => i64, -6;
+        i32, -6 => i64, -9;
+        i64, 0 => i128, -3;
+        i64, -3 => i128, -6;
+        i64, -6 => i128, -9;
+    );
+}
+
+#[test]
+fn from_widens_seconds_into_milliseconds() {
+    assert_eq!(TimestampMilliseconds::from(TimestampSeconds::new(1_700_000_000)), TimestampMilliseconds::new(1_700_000_000_000));
+    assert_eq!(Timestamp::<i64, -3>::from(Timestamp::<i32, 0>::new(-2)), Timestamp::new(-2_000));
+}
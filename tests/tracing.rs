@@ -0,0 +1,57 @@
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use timestamp_please::TimestampMilliseconds;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Records the `ts` field of every event
+#[derive(Clone, Default)]
+struct FieldRecorder {
+    values: Arc<Mutex<Vec<String>>>,
+}
+
+impl Visit for FieldRecorder {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "ts" {
+            if let Ok(mut values) = self.values.lock() {
+                values.push(format!("{value:?}"));
+            }
+        }
+    }
+}
+
+impl Subscriber for FieldRecorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut self.clone());
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn display_as_seconds_renders_in_tracing_fields() {
+    let recorder = FieldRecorder::default();
+    let timestamp = TimestampMilliseconds::new(1_700_000_000_123);
+    tracing::subscriber::with_default(recorder.clone(), || tracing::info!(ts = %timestamp.display_as_seconds()));
+    let values = recorder
+        .values
+        .lock()
+        .map(|values| values.clone())
+        .unwrap_or_default();
+    assert_eq!(values, ["1700000000.123"]);
+}